@@ -0,0 +1,99 @@
+// Compares the collecting input helpers with the lazy iterators and the byte integer parser.
+// Run with `cargo bench --bench parsing`, days without a puzzle input use a generated input.
+
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use advent_of_code::template::alloc::{self, Counting, Stats};
+use advent_of_code::template::{all_days, generators, inputs, paths};
+use advent_of_code::utils::input::{
+    blocks_of_lines, iter_block_lines, iter_fields, iter_lines, lines, parse_uint, signed_ints,
+    split_lines,
+};
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+/// Time spent on each variant of a case, spread over as many runs as fit.
+const BUDGET: Duration = Duration::from_millis(100);
+
+fn main() {
+    for day in all_days() {
+        let (input, source) = match inputs::read(Path::new(&paths::input(day))) {
+            Ok(input) => (input, "input"),
+            Err(_) => match generators::generate(day, None, 2025) {
+                Some(input) => (input, "generated"),
+                None => continue,
+            },
+        };
+        println!("Day {day} ({source}, {})", alloc::format_bytes(input.len()));
+        bench_day(&input);
+    }
+}
+
+fn bench_day(input: &str) {
+    compare("lines", || lines(input).len(), || iter_lines(input).count());
+    compare(
+        "fields",
+        || split_lines(input, ',').iter().map(Vec::len).sum::<usize>(),
+        || iter_lines(input).map(|l| iter_fields(l, ',').count()).sum(),
+    );
+    compare(
+        "blocks",
+        || blocks_of_lines(input).iter().map(Vec::len).sum::<usize>(),
+        || iter_block_lines(input).map(Iterator::count).sum(),
+    );
+    compare(
+        "ints",
+        || {
+            input
+                .split(|c: char| !c.is_ascii_digit() && c != '-')
+                .filter_map(|t| t.parse::<i64>().ok())
+                .collect::<Vec<_>>()
+                .iter()
+                .fold(0, |sum: i64, &n| sum.wrapping_add(n))
+        },
+        || {
+            signed_ints(input)
+                .flatten()
+                .fold(0, |sum, n| sum.wrapping_add(n))
+        },
+    );
+    let digit_runs = || input.split(|c: char| !c.is_ascii_digit());
+    compare(
+        "uint parse",
+        || digit_runs().filter_map(|t| t.parse::<u64>().ok()).count(),
+        || {
+            digit_runs()
+                .filter_map(|t| parse_uint(t.as_bytes()))
+                .count()
+        },
+    );
+}
+
+/// Runs a collecting and a lazy variant of the same work and prints their times and allocations.
+fn compare<T>(name: &str, collecting: impl Fn() -> T, lazy: impl Fn() -> T) {
+    let (collecting_time, collecting_stats) = bench(collecting);
+    let (lazy_time, lazy_stats) = bench(lazy);
+    let show = |stats: Option<Stats>| stats.map_or_else(String::new, |s| format!(" [{s}]"));
+    println!(
+        "  {name:<10} {collecting_time:>10.2?}{:<36} -> {lazy_time:>10.2?}{}",
+        show(collecting_stats),
+        show(lazy_stats)
+    );
+}
+
+/// Median time of a run and the allocations of the first one.
+fn bench<T>(f: impl Fn() -> T) -> (Duration, Option<Stats>) {
+    let (_, stats) = alloc::measure(|| black_box(f()));
+    let mut times = Vec::new();
+    let start = Instant::now();
+    while start.elapsed() < BUDGET || times.len() < 10 {
+        let run = Instant::now();
+        black_box(f());
+        times.push(run.elapsed());
+    }
+    times.sort_unstable();
+    (times[times.len() / 2], stats)
+}
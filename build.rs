@@ -0,0 +1,60 @@
+//! Generates the example tests of every solution binary from the examples manifests,
+//! see `src/template/examples.rs`. The tests are included with `example_tests!()`.
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/toml.rs"]
+mod toml;
+
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/config.rs"]
+mod config;
+
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/examples.rs"]
+mod examples;
+
+const BIN_DIR: &str = "src/bin";
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("examples");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let config =
+        config::Config::load(&root).unwrap_or_else(|e| panic!("invalid configuration: {e}"));
+    let examples_dir = config.examples_dir;
+
+    println!("cargo::rerun-if-changed={BIN_DIR}");
+    println!("cargo::rerun-if-changed={}", examples_dir.display());
+    println!("cargo::rerun-if-changed=aoc.toml");
+    println!("cargo::rerun-if-changed=Cargo.toml");
+
+    for entry in fs::read_dir(root.join(BIN_DIR)).unwrap() {
+        let file_name = entry.unwrap().file_name().into_string().unwrap();
+        let Some(bin) = file_name.strip_suffix(".rs") else {
+            continue;
+        };
+
+        // variants like `01_no_alloc` share the examples of their day.
+        let day = bin.get(..2).and_then(|day| day.parse::<u8>().ok());
+        let tests = match day {
+            Some(day) => generate(&examples_dir, day),
+            None => String::new(),
+        };
+
+        fs::write(out_dir.join(format!("{bin}.rs")), tests).unwrap();
+    }
+}
+
+fn generate(examples_dir: &Path, day: u8) -> String {
+    let manifest_path = examples_dir.join(format!("{day:02}.toml"));
+    let Ok(content) = fs::read_to_string(&manifest_path) else {
+        return String::new();
+    };
+
+    let examples = examples::parse_manifest(&content, day)
+        .unwrap_or_else(|e| panic!("{}: {e}", manifest_path.display()));
+    examples::generate_tests(&examples)
+}
@@ -1,22 +1,24 @@
-advent_of_code::solution!(1);
-
-const BOUND: i32 = 100;
-const START: i32 = 50;
+use advent_of_code::utils::input::{ParseError, parse_at};
+advent_of_code::solution!(
+    1,
+    params {
+        bound: i32 = 100,
+        start: i32 = 50
+    }
+);
 
-fn parse_input(input: &str) -> Vec<i32> {
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
     input
         .lines()
-        .filter_map(|l| {
-            let l = l.trim();
-            if l.is_empty() {
-                return None;
-            };
-            let (dir, val) = l.split_at(1);
-            let val = val.parse::<i32>().ok()?;
-            match dir {
-                "L" => Some(-val),
-                "R" => Some(val),
-                _ => None,
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            if let Some(val) = l.strip_prefix('L') {
+                Ok(-i32::from(parse_at::<u16>(input, val)?))
+            } else if let Some(val) = l.strip_prefix('R') {
+                Ok(i32::from(parse_at::<u16>(input, val)?))
+            } else {
+                Err(ParseError::at(input, l, "`L` or `R`"))
             }
         })
         .collect()
@@ -24,14 +26,14 @@ fn parse_input(input: &str) -> Vec<i32> {
 
 /// Calculate next value wrapping around bound 0..=99;
 #[inline(always)]
-fn wrap(current: i32, dir: i32) -> i32 {
-    (current + dir).rem_euclid(BOUND)
+fn wrap(current: i32, dir: i32, bound: i32) -> i32 {
+    (current + dir).rem_euclid(bound)
 }
 
-fn solve_part1(ins: &[i32]) -> u64 {
+fn solve_part1(ins: &[i32], params: &Params) -> u64 {
     ins.iter()
-        .scan(START, |prev, dir| {
-            *prev = wrap(*prev, *dir);
+        .scan(params.start, |prev, dir| {
+            *prev = wrap(*prev, *dir, params.bound);
             Some(*prev == 0)
         })
         .filter(|&passed_zero| passed_zero)
@@ -41,54 +43,41 @@ fn solve_part1(ins: &[i32]) -> u64 {
 /// Calculate next value wrapping around bound 0..=99 and return number of times we passed 0 in the
 /// process.
 #[inline(always)]
-fn wrap_count_passing_zero(current: i32, dir: i32) -> (i32, i32) {
-    let next = (current + dir).rem_euclid(BOUND);
+fn wrap_count_passing_zero(current: i32, dir: i32, bound: i32) -> (i32, i32) {
+    let next = (current + dir).rem_euclid(bound);
     if dir >= 0 {
         // Right case
-        (next, (current + dir) / BOUND)
+        (next, (current + dir) / bound)
     } else {
         // Left case
         // Correction needed for first passage through zero as this is not captured by whole
         // division. If current was already == 0, then this was already counted in the previous
         // cycle.
         let correction = ((current != 0) && (current + dir) <= 0) as i32;
-        (next, ((current + dir) / BOUND).abs() + correction)
+        (next, ((current + dir) / bound).abs() + correction)
     }
 }
 
-fn solve_part2(ins: &[i32]) -> u64 {
+fn solve_part2(ins: &[i32], params: &Params) -> u64 {
     ins.iter()
-        .scan(START, |prev, dir| {
-            let (prev_tmp, passing) = wrap_count_passing_zero(*prev, *dir);
+        .scan(params.start, |prev, dir| {
+            let (prev_tmp, passing) = wrap_count_passing_zero(*prev, *dir, params.bound);
             *prev = prev_tmp;
             Some(passing)
         })
         .sum::<i32>() as u64
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let ins = parse_input(input);
-    Some(solve_part1(&ins))
+pub fn part_one(input: &str, params: &Params) -> Result<u64, ParseError> {
+    let ins = parse_input(input)?;
+    Ok(solve_part1(&ins, params))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let ins = parse_input(input);
-    Some(solve_part2(&ins))
+pub fn part_two(input: &str, params: &Params) -> Result<u64, ParseError> {
+    let ins = parse_input(input)?;
+    Ok(solve_part2(&ins, params))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-}
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day01);
+advent_of_code::fuzz_tests!(parse_input);
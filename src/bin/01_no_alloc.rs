@@ -1,29 +1,35 @@
+use advent_of_code::utils::input::{ParseError, parse_at};
 advent_of_code::solution!(1);
 
 const BOUND: i32 = 100;
 const START: i32 = 50;
 
-pub fn part_one(input: &str) -> Option<i32> {
+/// The rotations of the input, parsed lazily to avoid collecting them.
+fn rotations(input: &str) -> impl Iterator<Item = Result<i32, ParseError>> + '_ {
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let (d, val) = match l.as_bytes()[0] {
+                b'L' => (-1, &l[1..]),
+                b'R' => (1, &l[1..]),
+                _ => return Err(ParseError::at(input, l, "`L` or `R`")),
+            };
+            Ok(d * i32::from(parse_at::<u16>(input, val)?))
+        })
+}
+
+pub fn part_one(input: &str) -> Result<i32, ParseError> {
     let mut count = 0;
     let mut current = START;
-    for l in input.lines() {
-        let l = l.trim();
-        if l.is_empty() {
-            continue;
-        };
-        let (dir, val) = l.split_at(1);
-        let val = val.parse::<i32>().unwrap();
-        let d = match dir {
-            "L" => -1,
-            "R" => 1,
-            _ => panic!(),
-        };
-        current = (current + val * d).rem_euclid(BOUND);
+    for val in rotations(input) {
+        current = (current + val?).rem_euclid(BOUND);
         if current == 0 {
             count += 1;
         }
     }
-    Some(count)
+    Ok(count)
 }
 
 #[inline(always)]
@@ -42,41 +48,17 @@ fn wrap_count_passing_zero(current: i32, dir: i32) -> (i32, i32) {
     }
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
+pub fn part_two(input: &str) -> Result<i32, ParseError> {
     let mut count = 0;
     let mut current = START;
-    for l in input.lines() {
-        let l = l.trim();
-        if l.is_empty() {
-            continue;
-        };
-        let (dir, val) = l.split_at(1);
-        let val = val.parse::<i32>().unwrap();
-        let d = match dir {
-            "L" => -1,
-            "R" => 1,
-            _ => 0,
-        };
-        let (next, passing) = wrap_count_passing_zero(current, val * d);
+    for val in rotations(input) {
+        let (next, passing) = wrap_count_passing_zero(current, val?);
         current = next;
         count += passing;
     }
-    Some(count)
+    Ok(count)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-}
+advent_of_code::example_tests!();
+advent_of_code::fuzz_tests!(|input: &str| rotations(input).collect::<Result<Vec<_>, _>>());
+advent_of_code::reference_tests!(Day01);
@@ -1,30 +1,33 @@
 use std::collections::HashSet;
+
+use advent_of_code::utils::input::{ParseError, iter_fields};
+use advent_of_code::utils::intervals::{parse_range, split_by_digit_length};
 advent_of_code::solution!(2);
 
-fn parse_input(input: &str) -> Vec<(usize, usize)> {
-    input
-        .trim()
-        .split(",")
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    iter_fields(input, ',')
         .map(|s| {
-            let (start, end) = s.split_once("-").unwrap();
-            let start = start.parse().unwrap();
-            let end = end.parse().unwrap();
-            (start, end)
+            let (start, end) = parse_range(input, s)?;
+            // IDs have no leading zeroes, so they are at least 1.
+            if start == 0 {
+                return Err(ParseError::at(input, s, "a range `a-b` with 1 <= a <= b"));
+            }
+            Ok((start, end))
         })
         .collect()
 }
 
 #[inline(always)]
-fn generate_twice_repeating_in_range(start: usize, end: usize) -> usize {
+fn generate_twice_repeating_in_range(start: u64, end: u64) -> u64 {
     let mut sum = 0;
     let max_digits = end.ilog10() + 1;
     let min_digits = start.ilog10() + 1;
-    let half_min_digits = (start.ilog10() + 1) / 2;
+    let half_min_digits = min_digits / 2;
     for k in half_min_digits..=(max_digits / 2) {
-        let pow_d = 10usize.pow(k);
+        let pow_d = 10u64.pow(k);
         let max_x = pow_d - 1;
         let min_x = if k == half_min_digits {
-            start / 10usize.pow(min_digits - k)
+            start / 10u64.pow(min_digits - k)
         } else {
             pow_d / 10
         };
@@ -40,50 +43,28 @@ fn generate_twice_repeating_in_range(start: usize, end: usize) -> usize {
     sum
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let ranges = parse_input(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let ranges = parse_input(input)?;
     let sum = ranges
         .into_iter()
         .map(|(start, end)| generate_twice_repeating_in_range(start, end))
-        .sum::<usize>();
-    Some(sum)
+        .sum::<u64>();
+    Ok(sum)
 }
 
-fn generate_n_repeating_in_range(start: usize, end: usize) -> usize {
+fn generate_n_repeating_in_range(start: u64, end: u64) -> u64 {
     // To avoid adding duplicate pairs like 1 & 1 & 1 & 1 = 1111 and 11 & 11 == 1111
-    let mut seen: HashSet<usize> = HashSet::new();
+    let mut seen: HashSet<u64> = HashSet::new();
     let mut sum = 0;
     // Precompute powers
-    let mut pow10 = [1usize; 20];
+    let mut pow10 = [1u64; 20];
     for i in 1..pow10.len() {
         pow10[i] = pow10[i - 1] * 10;
     }
-    // Get length bounds
-    // End
-    let max_digits = end.ilog10() + 1;
-    // Start
-    let min_digits = start.ilog10() + 1;
-    // Construct interim bounds to focus on same number of digits
+    // Split into ranges of the same number of digits
     // e.g. for start = 95 and end = 20065
-    // [start=95, 99, 999, 9999, end=20065]
-    let mut bounds = Vec::with_capacity(2 + (max_digits - min_digits) as usize);
-    bounds.push(start);
-    bounds.extend((0..(max_digits - min_digits)).scan(start, |state, x| {
-        *state = ((*state as f64) / 10.0f64.powf((min_digits + x) as f64)).ceil()
-            as usize
-            * 10usize.pow(min_digits) // e.g. 1000 -> deduct 1
-            - 1;
-        Some(*state)
-    }));
-    bounds.push(end);
-    let mut first = true;
-    for w in bounds.windows(2) {
-        let (mut start, end) = (w[0], w[1]);
-        // Adjust start boundaries of e.g. 999 -> 1000 after the first iteration.
-        // Such that start and end have same number of digits.
-        if !first {
-            start += 1;
-        }
+    // [95-99, 100-999, 1000-9999, 10000-20065]
+    for (start, end) in split_by_digit_length(start, end) {
         let n = start.ilog10() + 1;
         // Loop over sizes of repeating numbers
         for k in 1..=(n / 2) {
@@ -92,12 +73,8 @@ fn generate_n_repeating_in_range(start: usize, end: usize) -> usize {
             }
             let pow_d = pow10[k as usize];
             let max_x = pow_d - 1;
-            // In case of first iteration, start from k most significant digits of start.
-            let min_x = if first {
-                start / pow10[(n - k) as usize]
-            } else {
-                pow_d / 10
-            };
+            // Start from the k most significant digits of start.
+            let min_x = start / pow10[(n - k) as usize];
             // Nr of repeated additions of this subset of digits: x;
             let repeats = n / k;
             for x in min_x..=max_x {
@@ -113,33 +90,19 @@ fn generate_n_repeating_in_range(start: usize, end: usize) -> usize {
                 }
             }
         }
-        first = false;
     }
     sum
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let ranges = parse_input(input);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let ranges = parse_input(input)?;
     let sum = ranges
         .into_iter()
         .map(|(start, end)| generate_n_repeating_in_range(start, end))
-        .sum::<usize>();
-    Some(sum)
+        .sum::<u64>();
+    Ok(sum)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
-    }
-}
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day02);
+advent_of_code::fuzz_tests!(parse_input);
@@ -1,11 +1,19 @@
+use advent_of_code::utils::input::ParseError;
 advent_of_code::solution!(3);
 
-fn parse_input(input: &str) -> Vec<Vec<u8>> {
+fn parse_input(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
     input
         .lines()
         .map(|l| l.trim())
         .filter(|l| !l.is_empty())
-        .map(|l| l.bytes().map(|b| b - b'0').collect())
+        .map(|l| {
+            l.char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(d) => Ok(d as u8),
+                    None => Err(ParseError::at(input, &l[i..i + c.len_utf8()], "a digit")),
+                })
+                .collect()
+        })
         .collect()
 }
 
@@ -22,9 +30,9 @@ fn get_joltage(bank: &[u8], num_digits: usize) -> u64 {
         let end = n - num_digits + 1 + i;
         let mut max_val = 0u8;
         let mut max_idx = 0;
-        for j in start..end {
-            if bank[j] > max_val {
-                max_val = bank[j];
+        for (j, &val) in bank.iter().enumerate().take(end).skip(start) {
+            if val > max_val {
+                max_val = val;
                 max_idx = j;
             }
         }
@@ -35,30 +43,16 @@ fn get_joltage(bank: &[u8], num_digits: usize) -> u64 {
     res
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let banks = parse_input(input);
-    Some(banks.iter().map(|b| get_joltage(b, 2)).sum())
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let banks = parse_input(input)?;
+    Ok(banks.iter().map(|b| get_joltage(b, 2)).sum())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let banks = parse_input(input);
-    Some(banks.iter().map(|b| get_joltage(b, 12)).sum())
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let banks = parse_input(input)?;
+    Ok(banks.iter().map(|b| get_joltage(b, 12)).sum())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        let a: u64 = 3121910778619;
-        assert_eq!(result, Some(a));
-    }
-}
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day03);
+advent_of_code::fuzz_tests!(parse_input);
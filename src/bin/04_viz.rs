@@ -2,6 +2,9 @@ use gif::{Encoder, Frame, Repeat};
 use image::{ImageBuffer, Rgb, RgbImage};
 use std::fs::File;
 
+use advent_of_code::utils::grid::{Grid, Point};
+use advent_of_code::utils::input::ParseError;
+
 advent_of_code::solution!(4);
 
 // For visualization
@@ -13,144 +16,92 @@ const UPSCALE: u32 = 4;
 const NR_REMOVED_PER_FRAME: usize = 20;
 
 const MAX_COUNT: u8 = 4;
-const DIRS: [(i32, i32); 8] = [
-    (-1, 0),  // Left
-    (1, 0),   // Right
-    (0, -1),  // Up
-    (0, 1),   // Down
-    (-1, -1), // Up-Left
-    (1, -1),  // Up-Right
-    (-1, 1),  // Down-Left
-    (1, 1),   // Down-Right
-];
-
-fn parse_input(input: &str) -> (Vec<bool>, (usize, usize)) {
-    let n = input.split_once("\n").unwrap().0.len();
-    let m = input.lines().filter(|l| !l.is_empty()).count();
-    (
-        input
-            .lines()
-            .flat_map(|l| l.chars().map(|c| c == '@').collect::<Vec<bool>>())
-            .collect(),
-        (n, m),
-    )
-}
-
-#[inline(always)]
-fn idx(x: i32, y: i32, n: i32) -> i32 {
-    y * n + x
+fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, "`@` or `.`", |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
 }
 
 #[inline(always)]
-fn reverse_idx(idx: usize, n: usize) -> (usize, usize) {
-    (idx % n, idx / n)
+fn neighbours(grid: &Grid<bool>, p: Point) -> u8 {
+    grid.neighbours8(p).map(|q| grid[q] as u8).sum()
 }
 
-#[inline(always)]
-fn neighbours(grid: &[bool], x: usize, y: usize, n: i32, m: i32) -> u8 {
-    let mut count = 0;
-    for (dx, dy) in DIRS {
-        let nx = x as i32 + dx;
-        let ny = y as i32 + dy;
-        if nx >= 0 && nx < n && ny >= 0 && ny < m {
-            count += grid[idx(nx, ny, n) as usize] as u8;
+/// Neighbour counts of the rolls, 0 for empty cells.
+fn neighbour_counts(grid: &Grid<bool>) -> Grid<u8> {
+    let mut counts = Grid::new(grid.width(), grid.height(), 0);
+    for (p, &c) in grid.iter() {
+        if c {
+            counts[p] = neighbours(grid, p);
         }
     }
-    count
+    counts
 }
 
 /// Helper used for generating GIF frames in visualization
-fn generate_frame(
-    grid: &[bool],
-    n: usize,
-    m: usize,
-    updated: &[usize],
-) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
-    let mut img = RgbImage::new(n as u32, m as u32);
-    for i in 0..grid.len() {
-        let (x, y) = reverse_idx(i, n);
-        let pixel = if grid[i] { COLOR_TRUE } else { COLOR_FALSE };
+fn generate_frame(grid: &Grid<bool>, updated: &[Point]) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
+    let (n, m) = (grid.width() as u32, grid.height() as u32);
+    let mut img = RgbImage::new(n, m);
+    for ((x, y), &c) in grid.iter() {
+        let pixel = if c { COLOR_TRUE } else { COLOR_FALSE };
         img.put_pixel(x as u32, y as u32, pixel);
     }
-    for &i in updated {
-        let (x, y) = reverse_idx(i, n);
+    for &(x, y) in updated {
         let pixel = COLOR_UPDATED;
         img.put_pixel(x as u32, y as u32, pixel);
     }
-    let upscaled = image::imageops::resize(
+    image::imageops::resize(
         &img,
-        n as u32 * UPSCALE,
-        m as u32 * UPSCALE,
+        n * UPSCALE,
+        m * UPSCALE,
         image::imageops::FilterType::Nearest,
-    );
-    upscaled
+    )
 }
 
 pub fn part_one(_input: &str) -> Option<u64> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (mut grid, (n, m)) = parse_input(input);
-    let (n_i32, m_i32) = (n as i32, m as i32);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let mut grid = parse_input(input)?;
+    let (n, m) = (grid.width(), grid.height());
     let mut total = 0;
 
     let mut frames = Vec::new();
     let mut updated = vec![];
     // Start state for image
-    let img = generate_frame(&grid, n, m, &updated);
+    let img = generate_frame(&grid, &updated);
     frames.push(img.clone());
 
-    // Vector of neighbour counts
-    let mut counts = grid
-        .iter()
-        .enumerate()
-        .map(|(i, c)| {
-            if !c {
-                0
-            } else {
-                let (x, y) = reverse_idx(i, n);
-                neighbours(&grid, x, y, n_i32, m_i32)
-            }
-        })
-        .collect::<Vec<u8>>();
+    // Grid of neighbour counts
+    let mut counts = neighbour_counts(&grid);
 
-    // Vector of indices of next @ values to check
+    // Vector of points of next @ values to check
     let mut queue = grid
         .iter()
-        .enumerate()
-        .filter_map(|(i, c)| {
-            if !c {
-                None
-            } else {
-                if counts[i] < MAX_COUNT { Some(i) } else { None }
-            }
-        })
-        .collect::<Vec<usize>>();
+        .filter(|&(p, &c)| c && counts[p] < MAX_COUNT)
+        .map(|(p, _)| p)
+        .collect::<Vec<Point>>();
 
-    while let Some(i) = queue.pop() {
+    while let Some(p) = queue.pop() {
         total += 1;
-        grid[i] = false;
-        updated.push(i);
+        grid[p] = false;
+        updated.push(p);
         if updated.len() >= NR_REMOVED_PER_FRAME {
-            let img = generate_frame(&grid, n, m, &updated);
+            let img = generate_frame(&grid, &updated);
             frames.push(img);
             updated.clear();
         }
-        if counts[i] > 0 {
+        if counts[p] > 0 {
             // Update neighbour counts
-            let (x, y) = reverse_idx(i, n);
-            for (dx, dy) in DIRS {
-                let nx = x as i32 + dx;
-                let ny = y as i32 + dy;
-                if nx >= 0 && nx < n_i32 && ny >= 0 && ny < m_i32 {
-                    let ni = idx(nx, ny, n_i32) as usize;
-                    counts[ni] = counts[ni].saturating_sub(1);
-                    // Branching here on if counts[ni] > 0 is slower.
-                    // Check == MAX_COUNT - 1, not <= MAX_COUNT - 1 as then you duplicate elements in queue
-                    if counts[ni] == MAX_COUNT - 1 {
-                        queue.push(ni);
-                    }
+            for q in grid.neighbours8(p) {
+                counts[q] = counts[q].saturating_sub(1);
+                // Branching here on if counts[q] > 0 is slower.
+                // Check == MAX_COUNT - 1, not <= MAX_COUNT - 1 as then you duplicate elements in queue
+                if counts[q] == MAX_COUNT - 1 {
+                    queue.push(q);
                 }
             }
         }
@@ -159,11 +110,11 @@ pub fn part_two(input: &str) -> Option<u64> {
     // End state for image
     if !updated.is_empty() {
         // Add last frame
-        let img = generate_frame(&grid, n, m, &updated);
+        let img = generate_frame(&grid, &updated);
         frames.push(img.clone());
         updated.clear();
     }
-    let img = generate_frame(&grid, n, m, &updated);
+    let img = generate_frame(&grid, &updated);
     frames.push(img.clone());
     // --- Write frames to GIF ---
     let mut image = File::create(GIF_PATH).unwrap();
@@ -187,5 +138,6 @@ pub fn part_two(input: &str) -> Option<u64> {
         }
         encoder.write_frame(&frame).unwrap();
     }
-    Some(total)
+    Ok(total)
 }
+advent_of_code::fuzz_tests!(parse_input);
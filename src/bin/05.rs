@@ -1,97 +1,63 @@
-use anyhow::{Context, Result, anyhow};
+use advent_of_code::template::{Answer, Solution};
+use advent_of_code::utils::input::{ParseError, parse_at};
+use advent_of_code::utils::intervals::{IntervalSet, parse_ranges};
+use anyhow::{Context, Result};
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5 => Day05);
 
-fn parse_input(input: &str) -> Result<(Vec<(u64, u64)>, Vec<u64>)> {
+type Ranges = Vec<(u64, u64)>;
+
+fn parse_input(input: &str) -> Result<(Ranges, Vec<u64>)> {
     let (rngs, ings) = input
         .split_once("\n\n")
-        .ok_or_else(|| anyhow!("Couldnt parse line break"))?;
-    let rngs = rngs
-        .lines()
-        .map(|l| {
-            let (a, b) = l
-                .trim()
-                .split_once("-")
-                .ok_or_else(|| anyhow!("Couldn't split ranges on -"))?;
-            Ok((
-                a.parse::<u64>()
-                    .context("Failed to parse start val of range")?,
-                b.parse::<u64>()
-                    .context("Failed to parse right val of range")?,
-            ))
-        })
-        .collect::<Result<Vec<(u64, u64)>>>()?;
+        .ok_or_else(|| ParseError::end(input, "a blank line"))
+        .context("Couldnt parse line break")?;
+    let rngs = parse_ranges(input, rngs, '\n').context("Failed to parse ranges")?;
     let ings = ings
         .lines()
-        .map(|l| {
-            l.trim()
-                .parse::<u64>()
-                .context("Failed to parse ingredient")
-        })
+        .map(|l| parse_at::<u64>(input, l.trim()).context("Failed to parse ingredient"))
         .collect::<Result<Vec<u64>>>()?;
     Ok((rngs, ings))
 }
 
-fn merge_ranges(rngs: &mut [(u64, u64)]) -> Vec<(u64, u64)> {
-    // Sort by start value
-    rngs.sort_by_key(|r| r.0);
+struct Day05;
 
-    let mut merged: Vec<(u64, u64)> = Vec::new();
+impl Solution for Day05 {
+    /// The merged ranges and the ingredients.
+    type Parsed<'a> = (IntervalSet<u64>, Vec<u64>);
+    type Error = anyhow::Error;
 
-    for (a, b) in rngs {
-        if let Some((_, last_b)) = merged.last_mut() {
-            // last_b + 1 as the ranges are inclusive
-            if *a <= *last_b + 1 {
-                // Merge
-                let new_end = *b.max(last_b);
-                *last_b = new_end;
-            } else {
-                // Disjoint
-                merged.push((*a, *b));
-            }
-        } else {
-            // First iteration
-            merged.push((*a, *b));
-        }
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let (rngs, ings) = parse_input(input)?;
+        Ok((rngs.into_iter().collect(), ings))
     }
-    merged
-}
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (mut rngs, ings) = parse_input(input).ok()?;
-    let merged = merge_ranges(&mut rngs);
-    Some(
-        ings.iter()
-            .map(|&i| {
-                // Parition point returns the fist where index of the second partition (start where predicate = false) assuming merged is sorted based on the predicate.
-                // +- binary search
-                let p = merged.partition_point(|(_, b)| i > *b);
-                merged.get(p).map_or(0, |(a, _)| u64::from(i >= *a))
-            })
-            .sum::<u64>(),
-    )
-}
+    fn part_one((merged, ings): &Self::Parsed<'_>) -> Result<Answer> {
+        let fresh = ings.iter().filter(|&&i| merged.contains(i)).count();
+        Ok(fresh.into())
+    }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (mut rngs, _) = parse_input(input).ok()?;
-    let merged = merge_ranges(&mut rngs);
-    let count = merged.iter().fold(0, |acc, (a, b)| acc + b - a + 1);
-    Some(count)
+    fn part_two((merged, _): &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(merged.len().into())
+    }
 }
 
+advent_of_code::example_tests!(Day05);
+advent_of_code::reference_tests!(Day05 => Day05);
+advent_of_code::fuzz_tests!(Day05::parse);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::solution::solve;
 
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
+    fn test_invalid_input() {
+        let err = solve::<Day05>("3-5\n10-x\n\n1", 1).unwrap_err();
+        assert_eq!(err.to_string(), "Failed to parse ranges");
+        assert_eq!(
+            err.root_cause().to_string(),
+            "line 2, column 4: expected u64, found `x`"
+        );
     }
 }
@@ -1,3 +1,5 @@
+use advent_of_code::utils::columns::{Block, Columns};
+use advent_of_code::utils::input::{ParseError, parse_at};
 advent_of_code::solution!(6);
 
 #[derive(Debug, Clone, Copy)]
@@ -12,115 +14,79 @@ struct Instruction {
     op: Operation,
 }
 impl Instruction {
-    fn default(data: Vec<u64>) -> Self {
-        Self {
-            data,
-            op: Operation::Add,
-        }
-    }
     fn calculate_value(&self) -> u64 {
         match self.op {
             Operation::Add => self.data.iter().sum::<u64>(),
             Operation::Mul => self.data.iter().product::<u64>(),
         }
     }
+}
 
-    fn clear(&mut self) {
-        self.data.clear();
+fn parse_input(input: &str) -> Result<Columns<'_>, ParseError> {
+    let rows = input.lines().collect::<Vec<&str>>();
+    let Some((ops, numbers)) = rows.split_last() else {
+        return Err(ParseError::end(input, "a row of operators"));
+    };
+    // Only ASCII is allowed, so rows can be sliced by column.
+    let invalid = |row: &str, valid: fn(char) -> bool, expected: &str| {
+        row.char_indices()
+            .find(|&(_, c)| !valid(c))
+            .map(|(i, c)| ParseError::at(input, &row[i..i + c.len_utf8()], expected))
+    };
+    for row in numbers {
+        if let Some(err) = invalid(row, |c| c == ' ' || c.is_ascii_digit(), "a digit or ` `") {
+            return Err(err);
+        }
     }
+    if let Some(err) = invalid(ops, |c| matches!(c, ' ' | '+' | '*'), "`+`, `*` or ` `") {
+        return Err(err);
+    }
+    // A new column starts where an operator is in the last row
+    Ok(Columns::by_delimiter_row(numbers.to_vec(), ops))
 }
 
-fn parse_input(input: &str) -> (Vec<&str>, Vec<usize>, usize) {
-    let n_lines = input.lines().count();
-    let max_line_length = input.lines().map(|l| l.len()).max().unwrap();
-    // Get indices where new column starts == where operator is in the last row
-    let col_idx = input
-        .lines()
-        .last()
-        .unwrap()
-        .chars()
-        .enumerate()
-        .filter_map(|(i, c)| if c == '*' || c == '+' { Some(i) } else { None })
-        .chain(vec![max_line_length; 1])
-        .collect::<Vec<usize>>();
-    // Collect input into a vector or strings per row
-    let input = input.lines().collect::<Vec<&str>>();
-    (input, col_idx, n_lines)
+fn instruction(block: &Block, data: Vec<u64>) -> Instruction {
+    let op = match block.delimiter().trim() {
+        "*" => Operation::Mul,
+        _ => Operation::Add,
+    };
+    Instruction { data, op }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (input, col_idx, n_lines) = parse_input(input);
-    let mut ins = Instruction::default(vec![]);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let columns = parse_input(input)?;
     let mut total = 0;
-    for w in col_idx.windows(2) {
-        let (start, end) = (w[0], w[1]);
-        // New column so reset ins.
-        ins.clear();
-        // Set the operator based on the last row of input
-        match &input[n_lines - 1][start..=start] {
-            "+" => ins.op = Operation::Add,
-            "*" => ins.op = Operation::Mul,
-            _ => {}
-        }
-        // Collect digits
-        for &row in input[0..n_lines - 1].iter() {
-            let stop = end.min(row.len());
-            let c = row[start..stop].trim().parse::<u64>().unwrap();
-            ins.data.push(c);
-        }
-        total += ins.calculate_value();
+    for block in columns.blocks() {
+        // Numbers are read horizontally
+        let data = block
+            .rows()
+            .map(|cell| parse_at(input, cell.trim()))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+        total += instruction(&block, data).calculate_value();
     }
-    Some(total)
+    Ok(total)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (input, col_idx, n_lines) = parse_input(input);
-    let mut ins = Instruction::default(vec![]);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let columns = parse_input(input)?;
     let mut total = 0;
-    for w in col_idx.windows(2) {
-        let (start, end) = (w[0], w[1]);
-        // New column so reset ins.
-        ins.clear();
-        // Set the operator based on the last row of input
-        match &input[n_lines - 1][start..=start] {
-            "+" => ins.op = Operation::Add,
-            "*" => ins.op = Operation::Mul,
-            _ => {}
-        }
-        // Collect digits
-        for c in start..end {
-            let mut num = 0;
-            for row in input[0..n_lines - 1].iter() {
-                if c < row.len() {
-                    let val = &row[c..=c];
-                    if val != " " {
-                        num *= 10;
-                        num += val.parse::<u64>().unwrap();
-                    }
-                }
-            }
-            if num > 0 {
-                ins.data.push(num);
-            }
-        }
-        total += ins.calculate_value();
+    for block in columns.blocks() {
+        // Numbers are read vertically, digit by digit
+        let data = block
+            .columns()
+            .map(|column| {
+                column
+                    .bytes()
+                    .filter(|&b| b != b' ')
+                    .fold(0, |num, b| num * 10 + (b - b'0') as u64)
+            })
+            .filter(|&num| num > 0)
+            .collect();
+        total += instruction(&block, data).calculate_value();
     }
-    Some(total)
+    Ok(total)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263827));
-    }
-}
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day06);
+advent_of_code::fuzz_tests!(parse_input);
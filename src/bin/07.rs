@@ -1,12 +1,33 @@
+use advent_of_code::utils::input::ParseError;
 advent_of_code::solution!(7);
 
-fn parse_input(input: &str) -> (usize, Vec<Vec<char>>) {
-    let start = input.split_once("\n").unwrap().0.find("S").unwrap();
+fn parse_input(input: &str) -> Result<(usize, Vec<Vec<char>>), ParseError> {
     let grid = input
         .lines()
         .map(|l| l.trim().chars().collect())
         .collect::<Vec<Vec<char>>>();
-    (start, grid)
+    let first = input.lines().next().unwrap_or(input).trim();
+    let start = first
+        .find("S")
+        .ok_or_else(|| ParseError::at(input, first, "a row with the start `S`"))?;
+    // Beams are split to both sides, so splitters are never on the edge.
+    let n = grid[0].len();
+    for (l, row) in input.lines().zip(&grid) {
+        let l = l.trim();
+        if row.len() != n {
+            return Err(ParseError::at(input, l, format!("a row of {n} cells")));
+        }
+        for (i, (j, c)) in l.char_indices().enumerate() {
+            let expected = match c {
+                '.' | 'S' => continue,
+                '^' if i > 0 && i + 1 < n => continue,
+                '^' => "`.` at the edge",
+                _ => "`.`, `S` or `^`",
+            };
+            return Err(ParseError::at(input, &l[j..j + c.len_utf8()], expected));
+        }
+    }
+    Ok((start, grid))
 }
 
 #[inline(always)]
@@ -29,31 +50,18 @@ fn count_splits(start: usize, grid: &[Vec<char>]) -> (usize, u64) {
     (split_count, splits.iter().sum())
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (start, grid) = parse_input(input);
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let (start, grid) = parse_input(input)?;
     let (count, _) = count_splits(start, &grid);
-    Some(count)
+    Ok(count)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (start, grid) = parse_input(input);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let (start, grid) = parse_input(input)?;
     let (_, n_timelines) = count_splits(start, &grid);
-    Some(n_timelines)
+    Ok(n_timelines)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
-}
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day07);
+advent_of_code::fuzz_tests!(parse_input);
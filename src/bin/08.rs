@@ -1,74 +1,19 @@
+use advent_of_code::template::{Answer, NoAnswer, Overrides, Solution};
+use advent_of_code::utils::dsu::Dsu;
+use advent_of_code::utils::geom::Point3;
+use advent_of_code::utils::input::{ParseError, iter_lines, parse_pattern};
+use anyhow::Result;
 use itertools::Itertools;
-advent_of_code::solution!(8);
+// the example only makes 10 connections in part one.
+advent_of_code::solution!(8 => Day08, params { n_connections: usize = 1000 });
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct Junction {
-    x: u64,
-    y: u64,
-    z: u64,
-}
-
-impl Junction {
-    fn distance(&self, other: &Self) -> f64 {
-        ((self.x as f64 - other.x as f64).powf(2.)
-            + (self.y as f64 - other.y as f64).powf(2.)
-            + (self.z as f64 - other.z as f64).powf(2.))
-        .sqrt()
-    }
-}
-
-#[derive(Debug, Clone)]
-struct Dsu {
-    parent: Vec<usize>,
-    size: Vec<usize>,
-}
-
-impl Dsu {
-    fn new(n: usize) -> Self {
-        Self {
-            parent: (0..n).collect(),
-            size: vec![1; n],
-        }
-    }
-
-    #[inline]
-    fn find(&mut self, x: usize) -> usize {
-        if self.parent[x] != x {
-            self.parent[x] = self.find(self.parent[x]);
-        }
-        self.parent[x]
-    }
-
-    #[inline]
-    fn union(&mut self, a: usize, b: usize) -> bool {
-        let mut ra = self.find(a);
-        let mut rb = self.find(b);
-        if ra == rb {
-            // a and b are in the same component
-            return false;
-        }
-        // Make a represent the one with the largest size
-        if self.size[ra] < self.size[rb] {
-            std::mem::swap(&mut ra, &mut rb);
-        }
-        // Set component of b equal to a and update sizes
-        self.parent[rb] = ra;
-        self.size[ra] += self.size[rb];
-        true
-    }
-
-    #[inline]
-    fn component_size(&mut self, x: usize) -> usize {
-        let r = self.find(x);
-        self.size[r]
-    }
-}
+type Junction = Point3;
 
 #[derive(Debug, Copy, Clone)]
 struct Pair {
     a: usize,
     b: usize,
-    dist: f64,
+    dist: i64,
 }
 
 fn distance_vec(junctions: &[Junction]) -> Vec<Pair> {
@@ -77,101 +22,87 @@ fn distance_vec(junctions: &[Junction]) -> Vec<Pair> {
         .map(|(i, j)| Pair {
             a: i,
             b: j,
-            dist: junctions[i].distance(&junctions[j]),
+            dist: junctions[i].dist2(junctions[j]),
         })
         .collect::<Vec<Pair>>();
-    dist.sort_unstable_by(|a, b| {
-        a.dist
-            .partial_cmp(&b.dist)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
+    dist.sort_unstable_by_key(|p| p.dist);
     dist
 }
 
-fn parse_input(input: &str) -> Vec<Junction> {
-    input
-        .lines()
-        .filter_map(|l| {
-            let l = l.trim();
-            if l.is_empty() {
-                None
-            } else {
-                let (x, rest) = l.split_once(",").unwrap();
-                let (y, z) = rest.split_once(",").unwrap();
-                Some(Junction {
-                    x: x.parse().unwrap(),
-                    y: y.parse().unwrap(),
-                    z: z.parse().unwrap(),
-                })
-            }
+fn parse_input(input: &str) -> Result<Vec<Junction>, ParseError> {
+    iter_lines(input)
+        .map(|l| {
+            let (x, y, z) = parse_pattern::<(i64, i64, i64)>(input, l, "{},{},{}")?;
+            Ok(Junction::new(x, y, z))
         })
         .collect()
 }
 
-fn shortest_connections(
-    junctions: &[Junction],
-    dist: &mut [Pair],
-    n_connections: usize,
-) -> Option<u64> {
-    let n_junctions = junctions.len();
-    let mut dsu = Dsu::new(n_junctions);
-    let mut merges = 0;
+/// Sizes of the circuits after connecting the `n_connections` closest pairs, including pairs that
+/// are already in the same circuit.
+fn shortest_connections(junctions: &[Junction], dist: &[Pair], n_connections: usize) -> u64 {
+    let mut dsu = Dsu::new(junctions.len());
+    for p in dist.iter().take(n_connections) {
+        dsu.union(p.a, p.b);
+    }
+    // Product of the sizes of the 3 largest circuits
+    dsu.largest(3).iter().map(|&size| size as u64).product()
+}
 
+/// Connects the closest pairs until all junctions are joined in one circuit, returns the product
+/// of the x coordinates of the last pair.
+fn last_connection(junctions: &[Junction], dist: &[Pair]) -> Option<i64> {
+    let mut dsu = Dsu::new(junctions.len());
     for p in dist.iter() {
-        merges += 1;
-        if dsu.union(p.a, p.b) {
-            // Part 2: all junctions are joined in one component
-            if dsu.component_size(p.a) == n_junctions {
-                return Some(junctions[p.a].x * junctions[p.b].x);
-            }
-            // Part 1
-            if merges >= n_connections {
-                break;
-            }
-        }
-    }
-    // Part 1
-    // Get sizes of the roots
-    let mut sizes = vec![];
-    for i in 0..n_junctions {
-        let root = dsu.find(i);
-        if root == i {
-            sizes.push(dsu.size[root] as u64);
+        if dsu.union(p.a, p.b) && dsu.count() == 1 {
+            return Some(junctions[p.a].x * junctions[p.b].x);
         }
     }
-    // Sort in descending order and take product of top 3
-    sizes.sort_unstable_by(|a, b| b.cmp(a));
-    Some(sizes.iter().take(3).product::<u64>())
+    None
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let junc = parse_input(input);
-    let mut dist = distance_vec(&junc);
-    shortest_connections(&junc, &mut dist, 1000)
-}
+struct Day08;
+
+impl Solution for Day08 {
+    /// The junctions, their pairs sorted by distance and the parameters.
+    type Parsed<'a> = (Vec<Junction>, Vec<Pair>, Params);
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Self::parse_with(input, &Overrides::default())
+    }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let junc = parse_input(input);
-    let mut dist = distance_vec(&junc);
-    shortest_connections(&junc, &mut dist, usize::MAX)
+    fn parse_with<'a>(input: &'a str, overrides: &Overrides) -> Result<Self::Parsed<'a>> {
+        let params = Params::from_overrides(overrides)?;
+        let junc = parse_input(input)?;
+        let dist = distance_vec(&junc);
+        Ok((junc, dist, params))
+    }
+
+    fn part_one((junc, dist, params): &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(shortest_connections(junc, dist, params.n_connections).into())
+    }
+
+    fn part_two((junc, dist, _): &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(last_connection(junc, dist).ok_or(NoAnswer)?.into())
+    }
 }
 
+advent_of_code::example_tests!(Day08);
+advent_of_code::reference_tests!(Day08 => Day08, n_connections = 10);
+advent_of_code::fuzz_tests!(parse_input);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::solution::solve;
 
     #[test]
-    fn test_part_one() {
-        let input = &advent_of_code::template::read_file("examples", DAY);
-        let junc = parse_input(input);
-        let mut dist = distance_vec(&junc);
-        let result = shortest_connections(&junc, &mut dist, 10);
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
+    fn test_invalid_input() {
+        let err = solve::<Day08>("1,2,3\n4,5;6\n", 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected `,`, found `5;6`"
+        );
     }
 }
@@ -1,89 +1,70 @@
+use advent_of_code::template::NoAnswer;
+use advent_of_code::utils::geom::{BBox, Point2, Polygon};
+use advent_of_code::utils::input::{ParseError, parse_pattern};
+use anyhow::Result;
 use itertools::Itertools;
 advent_of_code::solution!(9);
 
-#[derive(Debug, Copy, Clone)]
-struct Corner {
-    x: u64,
-    y: u64,
-}
-
-fn parse_input(input: &str) -> Vec<Corner> {
+fn parse_input(input: &str) -> Result<Vec<Point2>, ParseError> {
     input
         .lines()
-        .filter_map(|l| {
-            let l = l.trim();
-            if l.is_empty() {
-                None
-            } else {
-                let (x, y) = l.split_once(",").unwrap();
-                let (x, y) = (x.parse().unwrap(), y.parse().unwrap());
-                Some(Corner { x, y })
-            }
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let (x, y) = parse_pattern(input, l, "{},{}")?;
+            Ok(Point2::new(x, y))
         })
         .collect()
 }
 
-fn area(c1: &Corner, c2: &Corner) -> u64 {
-    (c1.x.abs_diff(c2.x) + 1) * (c1.y.abs_diff(c2.y) + 1)
+fn area(c1: &Point2, c2: &Point2) -> u64 {
+    BBox::from_corners(*c1, *c2).area() as u64
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let corners = parse_input(input);
-    corners
+pub fn part_one(input: &str) -> Result<u64> {
+    let corners = parse_input(input)?;
+    let max = corners
         .iter()
         .combinations(2)
         .map(|c| area(c[0], c[1]))
-        .max()
+        .max();
+    Ok(max.ok_or(NoAnswer)?)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let corners = parse_input(input);
-    let n = corners.len();
+pub fn part_two(input: &str) -> Result<u64> {
+    let corners = parse_input(input)?;
     let mut areas = corners
         .iter()
         .combinations(2)
-        .map(|c| (c[0], c[1], area(c[0], c[1])))
-        .collect::<Vec<(&Corner, &Corner, u64)>>();
-    areas.sort_unstable_by_key(|(_, _, a)| *a);
-    let greens = (0..n)
-        .map(|i| {
-            let (c1, c2) = (corners[i], corners[(i + 1) % n]);
-            let (min_x, max_x) = (c1.x.min(c2.x), c1.x.max(c2.x));
-            let (min_y, max_y) = (c1.y.min(c2.y), c1.y.max(c2.y));
-            (min_x, max_x, min_y, max_y)
-        })
-        .collect::<Vec<(u64, u64, u64, u64)>>();
-    while let Some((c1, c2, a)) = areas.pop() {
-        // If valid break
-        let (min_x, max_x) = (c1.x.min(c2.x), c1.x.max(c2.x));
-        let (min_y, max_y) = (c1.y.min(c2.y), c1.y.max(c2.y));
-        let mut brk = false;
-        for (x1, x2, y1, y2) in greens.iter() {
-            if min_x < *x2 && min_y < *y2 && max_x > *x1 && max_y > *y1 {
-                brk = true;
-                break;
-            }
-        }
-        if !brk {
-            return Some(a);
+        .map(|c| (BBox::from_corners(*c[0], *c[1]), area(c[0], c[1])))
+        .collect::<Vec<(BBox, u64)>>();
+    areas.sort_unstable_by_key(|(_, a)| *a);
+    // The red tiles form a loop of green tiles.
+    let tiles = Polygon::new(corners).tiles();
+    while let Some((bbox, a)) = areas.pop() {
+        // The rectangle must not hold a tile outside, e.g. in the notch of a thin L shape.
+        if tiles.contains_box(&bbox) {
+            return Ok(a);
         }
     }
-    None
+    Err(NoAnswer.into())
 }
 
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day09);
+advent_of_code::fuzz_tests!(parse_input);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::Answer;
+    use advent_of_code::template::solution::solve;
 
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(50));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(24));
+    fn test_thin_notch() {
+        // The gap of the U is one tile wide, all of its tiles are on the edges.
+        let input = "0,0\n3,0\n3,5\n2,5\n2,1\n1,1\n1,5\n0,5\n";
+        assert_eq!(solve::<Solver>(input, 1).unwrap(), Answer::from(24_u64));
+        assert_eq!(solve::<Solver>(input, 2).unwrap(), Answer::from(24_u64));
     }
 }
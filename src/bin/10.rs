@@ -1,86 +1,101 @@
+use advent_of_code::template::NoAnswer;
+use advent_of_code::utils::bits::{BitSet, min_xor_count, solve_gf2};
+use advent_of_code::utils::input::{ParseError, parse_at, split_once_at};
+use anyhow::{Result, ensure};
 use good_lp::*;
 use rayon::prelude::*;
-use std::collections::{HashSet, VecDeque};
 
 advent_of_code::solution!(10);
 
+/// Part one searches at most `2^MAX_SEARCH` combinations of buttons or states of the lights.
+const MAX_SEARCH: usize = 24;
+
 #[derive(Debug, Clone)]
 struct Machine {
-    target: u64,
-    buttons: Vec<u64>,
+    target: BitSet,
+    buttons: Vec<BitSet>,
     joltage: Vec<u64>,
 }
 
-fn parse_input(input: &str) -> Vec<Machine> {
-    input
-        .lines()
-        .filter_map(|l| {
-            let l = l.trim();
-            if l.is_empty() {
-                None
-            } else {
-                let (target_str, rest) = l.split_once("] ").unwrap();
-                let target_str = &target_str[1..target_str.len()];
-                let mut target: u64 = 0;
-                for bit in 0..target_str.len() {
-                    let b = &target_str[bit..=bit];
-                    if b == "#" {
-                        target |= 1 << bit;
-                    }
-                }
-
-                let curly_idx = rest.find("{").unwrap();
-                let buttons_str = &rest[..curly_idx - 1];
-                let mut buttons = vec![];
-                for b in buttons_str.split_whitespace() {
-                    let mut button_out: u64 = 0;
-                    for button in b[1..b.len() - 1].split(',') {
-                        let parsed = button.parse::<u64>().unwrap();
-                        button_out |= 1 << parsed;
-                    }
-                    buttons.push(button_out)
-                }
-                let mut joltage: Vec<u64> = vec![];
-                for j in rest[curly_idx + 1..rest.len() - 1].split(',') {
-                    let j = j.parse().unwrap();
-                    joltage.push(j);
-                }
+fn parse_machine(input: &str, l: &str) -> Result<Machine, ParseError> {
+    let (target_str, rest) = split_once_at(input, l, "] ")?;
+    let target_str = target_str
+        .strip_prefix('[')
+        .ok_or_else(|| ParseError::at(input, target_str, "`[`"))?;
+    // Lights are bits of a bit set
+    let n_lights = target_str.len();
+    let mut target = BitSet::new(n_lights);
+    for (bit, c) in target_str.char_indices() {
+        match c {
+            '#' => target.set(bit, true),
+            '.' => {}
+            _ => {
+                let light = &target_str[bit..bit + c.len_utf8()];
+                return Err(ParseError::at(input, light, "`#` or `.`"));
+            }
+        }
+    }
 
-                Some(Machine {
-                    target,
-                    buttons,
-                    joltage,
-                })
+    let (buttons_str, joltage_str) = split_once_at(input, rest, "{")?;
+    let mut buttons = vec![];
+    for b in buttons_str.split_whitespace() {
+        let wiring = b
+            .strip_prefix('(')
+            .and_then(|b| b.strip_suffix(')'))
+            .ok_or_else(|| ParseError::at(input, b, "a button `(..)`"))?;
+        let mut button_out = BitSet::new(n_lights);
+        for button in wiring.split(',') {
+            let parsed = parse_at::<usize>(input, button)?;
+            if parsed >= n_lights {
+                let expected = format!("a light below {n_lights}");
+                return Err(ParseError::at(input, button, expected));
             }
-        })
+            button_out.set(parsed, true);
+        }
+        buttons.push(button_out)
+    }
+    let joltage_str = joltage_str
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::at(input, joltage_str, "`}`"))?;
+    let joltage = joltage_str
+        .split(',')
+        .map(|j| parse_at(input, j))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+
+    Ok(Machine {
+        target,
+        buttons,
+        joltage,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| parse_machine(input, l))
         .collect()
 }
 
 impl Machine {
-    fn min_toggles_lights(&self) -> Option<u64> {
-        let target = self.target;
-        // Edge case
-        if target == 0 {
-            return Some(0);
-        }
-        let mut queue = VecDeque::new();
-        let mut seen = HashSet::new();
-        queue.push_back((0u64, 0u64)); // (state, steps)
-        seen.insert(0u64);
-
-        while let Some((state, steps)) = queue.pop_front() {
-            for &btn in self.buttons.iter() {
-                let next = state ^ btn;
-                if next == target {
-                    return Some(steps + 1);
-                }
-                if !seen.contains(&next) {
-                    seen.insert(next);
-                    queue.push_back((next, steps + 1));
-                }
-            }
-        }
-        None
+    /// Each button is pressed at most once, as pressing it twice undoes it, so the fewest presses
+    /// are the minimum-weight solution of the linear system over GF(2). With more free variables
+    /// than lights, searching the states of the lights is cheaper than enumerating the solutions.
+    fn min_toggles_lights(&self) -> Result<u64> {
+        let solutions = solve_gf2(&self.buttons, &self.target).ok_or(NoAnswer)?;
+        let (free, lights) = (solutions.null_space.len(), self.target.len());
+        ensure!(
+            free.min(lights) <= MAX_SEARCH,
+            "{} buttons on {lights} lights leave {free} free variables, too many to search",
+            self.buttons.len()
+        );
+        let presses = if free > lights {
+            min_xor_count(&self.buttons, &self.target)
+        } else {
+            solutions.min_weight().map(|min| min.count_ones())
+        };
+        Ok(presses.ok_or(NoAnswer)? as u64)
     }
 
     /// Solve part 2 using a fast MILP solver (Highs).
@@ -101,7 +116,7 @@ impl Machine {
         for i in 0..m {
             let mut expr = Expression::from(0.0);
             for (j, xj) in x.iter().enumerate() {
-                if (self.buttons[j] >> i) & 1 == 1 {
+                if self.buttons[j].get(i) {
                     expr += xj;
                 }
             }
@@ -124,39 +139,55 @@ impl Machine {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let machines = parse_input(input);
-    let sum = machines
+pub fn part_one(input: &str) -> Result<u64> {
+    let machines = parse_input(input)?;
+    machines
         .par_iter()
-        .map(|m| m.min_toggles_lights().unwrap())
-        .sum();
-    Some(sum)
+        .map(|m| m.min_toggles_lights())
+        .sum::<Result<u64>>()
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let machines = parse_input(input);
-    machines.par_iter().map(|m| m.min_presses_good_lp()).reduce(
+pub fn part_two(input: &str) -> Result<u64> {
+    let machines = parse_input(input)?;
+    let sum = machines.par_iter().map(|m| m.min_presses_good_lp()).reduce(
         || Some(0),
         |acc, x| match (acc, x) {
             (Some(a), Some(b)) => Some(a + b),
             _ => None,
         },
-    )
+    );
+    Ok(sum.ok_or(NoAnswer)?)
 }
 
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day10, parts = [1]);
+advent_of_code::fuzz_tests!(parse_input);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::Answer;
+    use advent_of_code::template::solution::solve;
 
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
+    fn test_many_redundant_buttons() {
+        // 210 buttons on two lights leave 208 free variables, the lights are searched instead.
+        let buttons = ["(0)", "(1)", "(0,1)"].repeat(70).join(" ");
+        let input = format!("[##] {buttons} {{1,1}}\n");
+        assert_eq!(solve::<Solver>(&input, 1).unwrap(), Answer::from(1_u64));
     }
 
     #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
+    fn test_too_many_buttons() {
+        // 100 buttons on 30 lights leave 70 free variables, too many to search either way.
+        let buttons = (0..100)
+            .map(|i| format!("({})", i % 30))
+            .collect::<Vec<_>>();
+        let input = format!("[{}] {} {{0}}\n", "#".repeat(30), buttons.join(" "));
+        let err = solve::<Solver>(&input, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "100 buttons on 30 lights leave 70 free variables, too many to search"
+        );
     }
 }
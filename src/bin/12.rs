@@ -1,25 +1,27 @@
+use advent_of_code::utils::input::{ParseError, captures, parse_at};
 advent_of_code::solution!(12);
 
-pub fn part_one(input: &str) -> Option<usize> {
-    Some(
-        input
-            .trim()
-            .split("\n\n")
-            .last()
-            .unwrap()
-            .lines()
-            .filter(|l| {
-                let (wh, rest) = l.split_once(": ").unwrap();
-                let (w, h) = wh.split_once("x").unwrap();
-                let (w, h) = (w.parse::<u64>().unwrap(), h.parse::<u64>().unwrap());
-                let n = rest
-                    .split_whitespace()
-                    .map(|x| x.parse::<u64>().unwrap())
-                    .sum();
-                w * h / 9 >= n
-            })
-            .count(),
-    )
+/// The regions under the trees, given by their width, height and total number of presents. The
+/// shapes of the presents are not needed.
+fn parse_input(input: &str) -> Result<Vec<(u64, u64, u64)>, ParseError> {
+    let regions = input.trim().rsplit("\n\n").next().unwrap_or_default();
+    regions
+        .lines()
+        .map(|l| {
+            let [w, h, counts] = captures(input, l, "{}x{}: {}")?;
+            let (w, h) = (parse_at::<u32>(input, w)?, parse_at::<u32>(input, h)?);
+            let n = counts
+                .split_whitespace()
+                .map(|x| parse_at::<u32>(input, x).map(u64::from))
+                .sum::<Result<u64, ParseError>>()?;
+            Ok((w.into(), h.into(), n))
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let regions = parse_input(input)?;
+    Ok(regions.iter().filter(|&&(w, h, n)| w * h / 9 >= n).count())
 }
 
 #[allow(unused)]
@@ -27,19 +29,6 @@ pub fn part_two(input: &str) -> Option<u64> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
+advent_of_code::example_tests!();
+advent_of_code::reference_tests!(Day12);
+advent_of_code::fuzz_tests!(parse_input);
@@ -1,13 +1,13 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, clean, compare, download, generate, read, scaffold, solve, time, today, verify,
+};
 use args::{AppArguments, parse};
 
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
-
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::solve::Heap;
+    use advent_of_code::template::runner::InputSource;
+    use advent_of_code::template::{Day, is_valid_variant_name};
+    use std::path::PathBuf;
     use std::process;
 
     pub enum AppArguments {
@@ -21,12 +21,31 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            variant: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
-            dhat: bool,
+            heap: Heap,
             submit: Option<u8>,
+            variant: Option<String>,
+            input: InputSource,
+            params: Vec<String>,
+        },
+        Compare {
+            day: Day,
+        },
+        Verify {
+            day: Option<Day>,
+        },
+        Generate {
+            day: Day,
+            size: Option<usize>,
+            seed: Option<u64>,
+        },
+        Clean {
+            day: Day,
+            dry_run: bool,
         },
         All {
             release: bool,
@@ -35,9 +54,11 @@ mod args {
             all: bool,
             day: Option<Day>,
             store: bool,
+            alloc: bool,
+        },
+        Today {
+            wait: bool,
         },
-        #[cfg(feature = "today")]
-        Today,
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -50,11 +71,13 @@ mod args {
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let alloc = args.contains("--alloc");
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    alloc,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -67,15 +90,40 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                variant: parse_variant(&mut args)?,
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                heap: parse_heap(&mut args),
+                variant: parse_variant(&mut args)?,
+                params: args.values_from_str("--param")?,
+                input: parse_input_source(&mut args)?,
+            },
+            Some("compare") => AppArguments::Compare {
+                day: args.free_from_str()?,
+            },
+            Some("verify") => AppArguments::Verify {
+                day: args.opt_free_from_str()?,
+            },
+            Some("generate") => AppArguments::Generate {
+                day: args.free_from_str()?,
+                size: args.opt_value_from_str("--size")?,
+                seed: args.opt_value_from_str("--seed")?,
+            },
+            Some("clean") => {
+                let dry_run = args.contains("--dry-run");
+                let confirm = args.contains("--confirm");
+
+                AppArguments::Clean {
+                    day: args.free_from_str()?,
+                    dry_run: dry_run || !confirm,
+                }
+            }
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
             },
-            #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,6 +141,52 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parses the input source of `solve`. The optional number of `--example` is read last, once
+    /// all other arguments have been consumed.
+    fn parse_input_source(
+        args: &mut pico_args::Arguments,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let mut sources = vec![];
+        if let Some(path) = args.opt_value_from_str::<_, PathBuf>("--input")? {
+            sources.push(InputSource::File(path));
+        }
+        if args.contains("-") {
+            sources.push(InputSource::Stdin);
+        }
+        if args.contains("--example") {
+            sources.push(InputSource::Example(args.opt_free_from_str()?));
+        }
+
+        match sources.len() {
+            0 => Ok(InputSource::Inputs),
+            1 => Ok(sources.remove(0)),
+            _ => Err("only one of `--input`, `--example` and `-` can be used.".into()),
+        }
+    }
+
+    /// `--dhat` takes precedence over `--alloc`, both replace the global allocator.
+    fn parse_heap(args: &mut pico_args::Arguments) -> Heap {
+        let (dhat, alloc) = (args.contains("--dhat"), args.contains("--alloc"));
+        match (dhat, alloc) {
+            (true, _) => Heap::Dhat,
+            (false, true) => Heap::Counting,
+            (false, false) => Heap::System,
+        }
+    }
+
+    fn parse_variant(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let variant: Option<String> = args.opt_value_from_str("--variant")?;
+        match variant {
+            Some(name) if !is_valid_variant_name(&name) => Err(format!(
+                "invalid variant name `{name}`, expecting lowercase letters, digits and underscores."
+            )
+            .into()),
+            variant => Ok(variant),
+        }
+    }
 }
 
 fn main() {
@@ -103,15 +197,21 @@ fn main() {
         }
         Ok(args) => match args {
             AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                alloc,
+            } => time::handle(day, all, store, alloc),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                variant,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(day, overwrite, variant.as_deref());
                 if download {
                     download::handle(day);
                 }
@@ -119,26 +219,25 @@ fn main() {
             AppArguments::Solve {
                 day,
                 release,
-                dhat,
+                heap,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+                variant,
+                input,
+                params,
+            } => solve::handle(
+                day,
+                release,
+                heap,
+                submit,
+                variant.as_deref(),
+                &input,
+                &params,
+            ),
+            AppArguments::Compare { day } => compare::handle(day),
+            AppArguments::Verify { day } => verify::handle(day),
+            AppArguments::Generate { day, size, seed } => generate::handle(day, size, seed),
+            AppArguments::Clean { day, dry_run } => clean::handle(day, dry_run),
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,57 @@
+/// Day 1: turns the dial one click at a time.
+use anyhow::{Result, bail};
+
+use super::{lines, missing};
+use crate::template::{Answer, Solution};
+
+pub struct Day01;
+
+/// The clicks of each rotation, `-1` for left and `1` for right.
+fn rotations(input: &str) -> Result<Vec<(i64, u64)>> {
+    lines(input)
+        .map(|l| {
+            let l = l.trim();
+            let direction = match l.chars().next().ok_or_else(|| missing("direction"))? {
+                'L' => -1,
+                'R' => 1,
+                c => bail!("invalid direction `{c}`"),
+            };
+            Ok((direction, l[1..].parse()?))
+        })
+        .collect()
+}
+
+/// Counts how often the dial points at 0, after each rotation or after each click.
+fn count_zeroes(input: &str, every_click: bool) -> Result<Answer> {
+    let mut dial = 50;
+    let mut zeroes = 0_u64;
+    for (direction, clicks) in rotations(input)? {
+        for _ in 0..clicks {
+            dial = (dial + direction).rem_euclid(100);
+            if every_click && dial == 0 {
+                zeroes += 1;
+            }
+        }
+        if !every_click && dial == 0 {
+            zeroes += 1;
+        }
+    }
+    Ok(zeroes.into())
+}
+
+impl Solution for Day01 {
+    type Parsed<'a> = &'a str;
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part_one(input: &&str) -> Result<Answer> {
+        count_zeroes(input, false)
+    }
+
+    fn part_two(input: &&str) -> Result<Answer> {
+        count_zeroes(input, true)
+    }
+}
@@ -0,0 +1,53 @@
+/// Day 2: checks every id of every range.
+use anyhow::Result;
+
+use super::{missing, number};
+use crate::template::{Answer, Solution};
+
+pub struct Day02;
+
+fn ranges(input: &str) -> Result<Vec<(u64, u64)>> {
+    input
+        .trim()
+        .split(',')
+        .map(|range| {
+            let (start, end) = range.split_once('-').ok_or_else(|| missing("`-`"))?;
+            Ok((number(start)?, number(end)?))
+        })
+        .collect()
+}
+
+/// Whether `id` is some sequence of digits repeated `times` times.
+fn repeats(id: &str, times: usize) -> bool {
+    id.len().is_multiple_of(times) && id == id[..id.len() / times].repeat(times)
+}
+
+/// Sums the invalid ids of all ranges.
+fn sum_invalid(input: &str, invalid: impl Fn(&str) -> bool) -> Result<Answer> {
+    let mut sum = 0;
+    for (start, end) in ranges(input)? {
+        for id in start..=end {
+            if invalid(&id.to_string()) {
+                sum += id;
+            }
+        }
+    }
+    Ok(sum.into())
+}
+
+impl Solution for Day02 {
+    type Parsed<'a> = &'a str;
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part_one(input: &&str) -> Result<Answer> {
+        sum_invalid(input, |id| repeats(id, 2))
+    }
+
+    fn part_two(input: &&str) -> Result<Answer> {
+        sum_invalid(input, |id| (2..=id.len()).any(|times| repeats(id, times)))
+    }
+}
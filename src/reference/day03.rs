@@ -0,0 +1,76 @@
+/// Day 3: finds the largest joltage of each bank by trying every choice of batteries, memoised on
+/// the position in the bank and the number of batteries left to turn on.
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+
+use super::lines;
+use crate::template::{Answer, Solution};
+
+pub struct Day03;
+
+fn banks(input: &str) -> Result<Vec<Vec<u64>>> {
+    lines(input)
+        .map(|l| {
+            l.trim()
+                .chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(d) => Ok(d as u64),
+                    None => bail!("invalid battery `{c}`"),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The largest number formed by `k` of the batteries `bank[i..]` in order.
+fn largest(
+    bank: &[u64],
+    i: usize,
+    k: usize,
+    memo: &mut HashMap<(usize, usize), Option<u64>>,
+) -> Option<u64> {
+    if k == 0 {
+        return Some(0);
+    }
+    if i == bank.len() {
+        return None;
+    }
+    if let Some(&result) = memo.get(&(i, k)) {
+        return result;
+    }
+    let skip = largest(bank, i + 1, k, memo);
+    let take =
+        largest(bank, i + 1, k - 1, memo).map(|rest| bank[i] * 10_u64.pow(k as u32 - 1) + rest);
+    let result = skip.max(take);
+    memo.insert((i, k), result);
+    result
+}
+
+fn total_joltage(input: &str, k: usize) -> Result<Answer> {
+    let mut total = 0;
+    for bank in banks(input)? {
+        match largest(&bank, 0, k, &mut HashMap::new()) {
+            Some(joltage) => total += joltage,
+            None => bail!("bank with fewer than {k} batteries"),
+        }
+    }
+    Ok(total.into())
+}
+
+impl Solution for Day03 {
+    type Parsed<'a> = &'a str;
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part_one(input: &&str) -> Result<Answer> {
+        total_joltage(input, 2)
+    }
+
+    fn part_two(input: &&str) -> Result<Answer> {
+        total_joltage(input, 12)
+    }
+}
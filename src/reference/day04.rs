@@ -0,0 +1,72 @@
+/// Day 4: counts the neighbours of every roll and removes accessible rolls round by round.
+use anyhow::Result;
+
+use super::lines;
+use crate::template::{Answer, Overrides, Solution};
+
+pub struct Day04;
+
+fn grid(input: &str) -> Vec<Vec<bool>> {
+    lines(input)
+        .map(|l| l.trim().chars().map(|c| c == '@').collect())
+        .collect()
+}
+
+/// The rolls with fewer than `max_count` rolls among their eight neighbours.
+fn accessible(grid: &[Vec<bool>], max_count: usize) -> Vec<(usize, usize)> {
+    let mut rolls = vec![];
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            if !grid[y][x] {
+                continue;
+            }
+            let mut neighbours = 0;
+            for ny in y.saturating_sub(1)..=y + 1 {
+                for nx in x.saturating_sub(1)..=x + 1 {
+                    let is_roll = grid.get(ny).and_then(|row| row.get(nx)) == Some(&true);
+                    if (nx, ny) != (x, y) && is_roll {
+                        neighbours += 1;
+                    }
+                }
+            }
+            if neighbours < max_count {
+                rolls.push((x, y));
+            }
+        }
+    }
+    rolls
+}
+
+impl Solution for Day04 {
+    /// The grid and the neighbour count below which a roll is accessible.
+    type Parsed<'a> = (Vec<Vec<bool>>, usize);
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, overrides: &Overrides) -> Result<Self::Parsed<'a>> {
+        overrides.check_keys(&["max_count"])?;
+        Ok((grid(input), overrides.get("max_count")?.unwrap_or(4)))
+    }
+
+    fn part_one((grid, max_count): &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(accessible(grid, *max_count).len().into())
+    }
+
+    fn part_two((grid, max_count): &Self::Parsed<'_>) -> Result<Answer> {
+        let mut grid = grid.clone();
+        let mut removed = 0;
+        loop {
+            let rolls = accessible(&grid, *max_count);
+            if rolls.is_empty() {
+                return Ok(removed.into());
+            }
+            removed += rolls.len();
+            for (x, y) in rolls {
+                grid[y][x] = false;
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/// Day 5: checks every id against every range and counts fresh ids one by one.
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use super::{lines, missing, number};
+use crate::template::{Answer, Solution};
+
+pub struct Day05;
+
+type Ranges = Vec<(u64, u64)>;
+
+fn database(input: &str) -> Result<(Ranges, Vec<u64>)> {
+    let (ranges, ids) = input
+        .split_once("\n\n")
+        .ok_or_else(|| missing("blank line"))?;
+    let ranges = lines(ranges)
+        .map(|l| {
+            let (start, end) = l.split_once('-').ok_or_else(|| missing("`-`"))?;
+            Ok((number(start)?, number(end)?))
+        })
+        .collect::<Result<_>>()?;
+    let ids = lines(ids).map(number).collect::<Result<_>>()?;
+    Ok((ranges, ids))
+}
+
+impl Solution for Day05 {
+    type Parsed<'a> = (Ranges, Vec<u64>);
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        database(input)
+    }
+
+    fn part_one((ranges, ids): &Self::Parsed<'_>) -> Result<Answer> {
+        let fresh = ids
+            .iter()
+            .filter(|&&id| ranges.iter().any(|&(start, end)| start <= id && id <= end))
+            .count();
+        Ok(fresh.into())
+    }
+
+    fn part_two((ranges, _): &Self::Parsed<'_>) -> Result<Answer> {
+        let fresh: HashSet<u64> = ranges
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .collect();
+        Ok(fresh.len().into())
+    }
+}
@@ -0,0 +1,88 @@
+/// Day 6: cuts the worksheet into problems at the blank columns and reads the numbers row by row
+/// for part one and column by column for part two.
+use anyhow::{Result, bail};
+
+use super::{lines, missing, number};
+use crate::template::{Answer, Solution};
+
+pub struct Day06;
+
+/// The cells of a problem, the last row holds the operator.
+type Problem = Vec<Vec<char>>;
+
+fn problems(input: &str) -> Vec<Problem> {
+    let rows: Vec<&str> = lines(input).collect();
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let grid: Vec<Vec<char>> = rows
+        .iter()
+        .map(|r| format!("{r:<width$}").chars().collect())
+        .collect();
+
+    let mut problems = vec![];
+    let mut problem: Problem = vec![vec![]; grid.len()];
+    for x in 0..=width {
+        if x == width || grid.iter().all(|row| row[x] == ' ') {
+            if !problem[0].is_empty() {
+                problems.push(problem);
+            }
+            problem = vec![vec![]; grid.len()];
+        } else {
+            for (cells, row) in problem.iter_mut().zip(&grid) {
+                cells.push(row[x]);
+            }
+        }
+    }
+    problems
+}
+
+/// Applies the operator of a problem to its numbers.
+fn solve(problem: &Problem, numbers: Vec<u64>) -> Result<u64> {
+    let operator = problem.last().ok_or_else(|| missing("operator"))?;
+    match operator.iter().find(|c| **c != ' ') {
+        Some('+') => Ok(numbers.iter().sum()),
+        Some('*') => Ok(numbers.iter().product()),
+        c => bail!("invalid operator {c:?}"),
+    }
+}
+
+impl Solution for Day06 {
+    type Parsed<'a> = Vec<Problem>;
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(problems(input))
+    }
+
+    fn part_one(problems: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut total = 0;
+        for problem in problems {
+            let rows = &problem[..problem.len() - 1];
+            let numbers = rows
+                .iter()
+                .map(|row| number(&row.iter().collect::<String>()))
+                .collect::<Result<_>>()?;
+            total += solve(problem, numbers)?;
+        }
+        Ok(total.into())
+    }
+
+    fn part_two(problems: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut total = 0;
+        for problem in problems {
+            let rows = &problem[..problem.len() - 1];
+            let numbers = (0..problem[0].len())
+                .map(|x| {
+                    number(
+                        &rows
+                            .iter()
+                            .map(|row| row[x])
+                            .filter(|&c| c != ' ')
+                            .collect::<String>(),
+                    )
+                })
+                .collect::<Result<_>>()?;
+            total += solve(problem, numbers)?;
+        }
+        Ok(total.into())
+    }
+}
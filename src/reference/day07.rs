@@ -0,0 +1,56 @@
+/// Day 7: moves the beams down row by row for part one and follows every single timeline for part
+/// two.
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+
+use super::{lines, missing};
+use crate::template::{Answer, Solution};
+
+pub struct Day07;
+
+/// Follows the timelines of a particle at `x` in `row`, the particle leaves at the bottom.
+fn timelines(grid: &[Vec<char>], row: usize, x: usize) -> u64 {
+    match grid.get(row).and_then(|r| r.get(x)) {
+        None => 1,
+        Some('^') => timelines(grid, row + 1, x - 1) + timelines(grid, row + 1, x + 1),
+        Some(_) => timelines(grid, row + 1, x),
+    }
+}
+
+impl Solution for Day07 {
+    /// The grid and the column of the start.
+    type Parsed<'a> = (Vec<Vec<char>>, usize);
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let grid: Vec<Vec<char>> = lines(input).map(|l| l.chars().collect()).collect();
+        let start = grid
+            .first()
+            .and_then(|row| row.iter().position(|&c| c == 'S'))
+            .ok_or_else(|| missing("start"))?;
+        Ok((grid, start))
+    }
+
+    fn part_one((grid, start): &Self::Parsed<'_>) -> Result<Answer> {
+        let mut beams = BTreeSet::from([*start]);
+        let mut splits = 0_u64;
+        for row in &grid[1..] {
+            let mut next = BTreeSet::new();
+            for x in beams {
+                if row[x] == '^' {
+                    splits += 1;
+                    next.extend([x - 1, x + 1]);
+                } else {
+                    next.insert(x);
+                }
+            }
+            beams = next;
+        }
+        Ok(splits.into())
+    }
+
+    fn part_two((grid, start): &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(timelines(grid, 1, *start).into())
+    }
+}
@@ -0,0 +1,82 @@
+/// Day 8: connects the pairs of junction boxes by exact distance and relabels whole circuits on
+/// every connection.
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use super::{lines, missing, numbers};
+use crate::template::{Answer, Overrides, Solution};
+
+pub struct Day08;
+
+/// Pairs of boxes, closest first. Pairs at the same distance keep their order in the input.
+fn pairs(boxes: &[[i64; 3]]) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+    for i in 0..boxes.len() {
+        for j in i + 1..boxes.len() {
+            pairs.push((i, j));
+        }
+    }
+    let distance = |&(i, j): &(usize, usize)| -> i64 {
+        (0..3).map(|k| (boxes[i][k] - boxes[j][k]).pow(2)).sum()
+    };
+    pairs.sort_by_key(distance);
+    pairs
+}
+
+/// Connects `a` and `b` by moving all boxes of the circuit of `b` to the circuit of `a`.
+fn connect(circuits: &mut [usize], a: usize, b: usize) {
+    let (from, to) = (circuits[b], circuits[a]);
+    for circuit in circuits.iter_mut() {
+        if *circuit == from {
+            *circuit = to;
+        }
+    }
+}
+
+impl Solution for Day08 {
+    /// The boxes and the number of connections of part one.
+    type Parsed<'a> = (Vec<[i64; 3]>, usize);
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, overrides: &Overrides) -> Result<Self::Parsed<'a>> {
+        overrides.check_keys(&["n_connections"])?;
+        let boxes = lines(input)
+            .map(|l| {
+                numbers(l, ',')?
+                    .try_into()
+                    .map_err(|_| missing("coordinate"))
+            })
+            .collect::<Result<_>>()?;
+        Ok((boxes, overrides.get("n_connections")?.unwrap_or(1000)))
+    }
+
+    fn part_one((boxes, n_connections): &Self::Parsed<'_>) -> Result<Answer> {
+        let mut circuits: Vec<usize> = (0..boxes.len()).collect();
+        for (a, b) in pairs(boxes).into_iter().take(*n_connections) {
+            connect(&mut circuits, a, b);
+        }
+        let mut sizes: HashMap<usize, u64> = HashMap::new();
+        for circuit in circuits {
+            *sizes.entry(circuit).or_default() += 1;
+        }
+        let mut sizes: Vec<u64> = sizes.into_values().collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(sizes.iter().take(3).product::<u64>().into())
+    }
+
+    fn part_two((boxes, _): &Self::Parsed<'_>) -> Result<Answer> {
+        let mut circuits: Vec<usize> = (0..boxes.len()).collect();
+        for (a, b) in pairs(boxes) {
+            connect(&mut circuits, a, b);
+            if circuits.iter().all(|&c| c == circuits[0]) {
+                return Ok((boxes[a][0] * boxes[b][0]).into());
+            }
+        }
+        Err(missing("connection joining all boxes"))
+    }
+}
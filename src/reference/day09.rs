@@ -0,0 +1,96 @@
+/// Day 9: marks every tile inside the loop of red tiles and checks every rectangle tile by tile,
+/// with prefix sums to keep it bearable.
+use anyhow::{Result, bail};
+
+use super::{lines, missing, numbers};
+use crate::template::{Answer, Solution};
+
+pub struct Day09;
+
+/// Tiles of larger inputs are too many to mark one by one.
+const MAX_TILES: u64 = 10_000_000;
+
+fn area((x1, y1): (u64, u64), (x2, y2): (u64, u64)) -> u64 {
+    (x1.abs_diff(x2) + 1) * (y1.abs_diff(y2) + 1)
+}
+
+/// Whether the tile is red or green, i.e. on the loop or inside of it.
+fn is_inside(red: &[(u64, u64)], (x, y): (u64, u64)) -> bool {
+    let mut crossings = 0;
+    for i in 0..red.len() {
+        let (a, b) = (red[i], red[(i + 1) % red.len()]);
+        let (min_x, max_x) = (a.0.min(b.0), a.0.max(b.0));
+        let (min_y, max_y) = (a.1.min(b.1), a.1.max(b.1));
+        if (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) {
+            return true;
+        }
+        // Count the vertical edges to the right, each edge includes its lower end only.
+        if a.0 == b.0 && a.0 > x && min_y <= y && y < max_y {
+            crossings += 1;
+        }
+    }
+    crossings % 2 == 1
+}
+
+impl Solution for Day09 {
+    type Parsed<'a> = Vec<(u64, u64)>;
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        lines(input)
+            .map(|l| match numbers(l, ',')?[..] {
+                [x, y] => Ok((x, y)),
+                _ => bail!("invalid tile `{l}`"),
+            })
+            .collect()
+    }
+
+    fn part_one(red: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut largest = 0;
+        for &a in red {
+            for &b in red {
+                largest = largest.max(area(a, b));
+            }
+        }
+        Ok(largest.into())
+    }
+
+    fn part_two(red: &Self::Parsed<'_>) -> Result<Answer> {
+        let max_x = red
+            .iter()
+            .map(|t| t.0)
+            .max()
+            .ok_or_else(|| missing("tiles"))?;
+        let max_y = red
+            .iter()
+            .map(|t| t.1)
+            .max()
+            .ok_or_else(|| missing("tiles"))?;
+        if (max_x + 1) * (max_y + 1) > MAX_TILES {
+            bail!("too many tiles for the reference");
+        }
+
+        // `inside[y][x]` counts the tiles inside in the rectangle from (0, 0) to (x - 1, y - 1).
+        let (w, h) = (max_x as usize + 1, max_y as usize + 1);
+        let mut inside = vec![vec![0_u64; w + 1]; h + 1];
+        for y in 0..h {
+            for x in 0..w {
+                let tile = is_inside(red, (x as u64, y as u64)) as u64;
+                inside[y + 1][x + 1] = tile + inside[y][x + 1] + inside[y + 1][x] - inside[y][x];
+            }
+        }
+
+        let mut largest = 0;
+        for &a in red {
+            for &b in red {
+                let (x1, x2) = (a.0.min(b.0) as usize, a.0.max(b.0) as usize + 1);
+                let (y1, y2) = (a.1.min(b.1) as usize, a.1.max(b.1) as usize + 1);
+                let count = inside[y2][x2] + inside[y1][x1] - inside[y1][x2] - inside[y2][x1];
+                if count == area(a, b) {
+                    largest = largest.max(count);
+                }
+            }
+        }
+        Ok(largest.into())
+    }
+}
@@ -0,0 +1,114 @@
+/// Day 10: tries every set of buttons for the lights and every number of presses of every button
+/// for the joltage, memoised on the remaining joltage.
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+
+use super::{lines, missing, numbers};
+use crate::template::{Answer, Solution};
+
+pub struct Day10;
+
+pub struct Machine {
+    lights: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+    joltage: Vec<u64>,
+}
+
+fn machine(line: &str) -> Result<Machine> {
+    let mut lights = None;
+    let mut buttons = vec![];
+    let mut joltage = None;
+    for part in line.split_whitespace() {
+        let inner = &part[1..part.len() - 1];
+        match part.chars().next() {
+            Some('[') => lights = Some(inner.chars().map(|c| c == '#').collect()),
+            Some('(') => buttons.push(numbers(inner, ',')?),
+            Some('{') => joltage = Some(numbers(inner, ',')?),
+            _ => bail!("invalid machine `{line}`"),
+        }
+    }
+    Ok(Machine {
+        lights: lights.ok_or_else(|| missing("lights"))?,
+        buttons,
+        joltage: joltage.ok_or_else(|| missing("joltage"))?,
+    })
+}
+
+/// The fewest presses of the buttons `buttons[i..]` that increase the counters by `remaining`.
+fn fewest_presses(
+    buttons: &[Vec<usize>],
+    i: usize,
+    remaining: Vec<u64>,
+    memo: &mut HashMap<(usize, Vec<u64>), Option<u64>>,
+) -> Option<u64> {
+    if remaining.iter().all(|&r| r == 0) {
+        return Some(0);
+    }
+    if i == buttons.len() {
+        return None;
+    }
+    if let Some(&fewest) = memo.get(&(i, remaining.clone())) {
+        return fewest;
+    }
+    let max_presses = buttons[i].iter().map(|&j| remaining[j]).min().unwrap_or(0);
+    let mut fewest = None;
+    for presses in 0..=max_presses {
+        let mut rest = remaining.clone();
+        for &j in &buttons[i] {
+            rest[j] -= presses;
+        }
+        if let Some(more) = fewest_presses(buttons, i + 1, rest, memo) {
+            fewest = Some(fewest.map_or(presses + more, |f: u64| f.min(presses + more)));
+        }
+    }
+    memo.insert((i, remaining), fewest);
+    fewest
+}
+
+impl Solution for Day10 {
+    type Parsed<'a> = Vec<Machine>;
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        lines(input).map(machine).collect()
+    }
+
+    fn part_one(machines: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut total = 0;
+        for machine in machines {
+            let n = machine.buttons.len();
+            let fewest = (0_u32..1 << n)
+                .filter(|pressed| {
+                    let mut lights = vec![false; machine.lights.len()];
+                    for (i, button) in machine.buttons.iter().enumerate() {
+                        if pressed & (1 << i) != 0 {
+                            for &j in button {
+                                lights[j] = !lights[j];
+                            }
+                        }
+                    }
+                    lights == machine.lights
+                })
+                .map(u32::count_ones)
+                .min()
+                .ok_or_else(|| missing("button presses for the lights"))?;
+            total += fewest as u64;
+        }
+        Ok(total.into())
+    }
+
+    fn part_two(machines: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut total = 0;
+        for machine in machines {
+            total += fewest_presses(
+                &machine.buttons,
+                0,
+                machine.joltage.clone(),
+                &mut HashMap::new(),
+            )
+            .ok_or_else(|| missing("button presses for the joltage"))?;
+        }
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,60 @@
+/// Day 11: walks every single path through the devices.
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use super::{lines, missing};
+use crate::template::{Answer, Solution};
+
+pub struct Day11;
+
+type Devices<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+/// Counts the paths from `device` to `out` that visit all of `required`, skipping devices that are
+/// already on the path.
+fn paths<'a>(
+    devices: &Devices<'a>,
+    device: &'a str,
+    path: &mut Vec<&'a str>,
+    required: &[&str],
+) -> u64 {
+    if device == "out" {
+        return required.iter().all(|r| path.contains(r)) as u64;
+    }
+    let mut count = 0;
+    for &next in devices.get(device).into_iter().flatten() {
+        if !path.contains(&next) {
+            path.push(next);
+            count += paths(devices, next, path, required);
+            path.pop();
+        }
+    }
+    count
+}
+
+fn count_paths(devices: &Devices<'_>, from: &str, required: &[&str]) -> Result<Answer> {
+    let (&from, _) = devices.get_key_value(from).ok_or_else(|| missing(from))?;
+    Ok(paths(devices, from, &mut vec![from], required).into())
+}
+
+impl Solution for Day11 {
+    type Parsed<'a> = Devices<'a>;
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        lines(input)
+            .map(|l| {
+                let (device, outputs) = l.split_once(": ").ok_or_else(|| missing("`: `"))?;
+                Ok((device, outputs.split_whitespace().collect()))
+            })
+            .collect()
+    }
+
+    fn part_one(devices: &Self::Parsed<'_>) -> Result<Answer> {
+        count_paths(devices, "you", &[])
+    }
+
+    fn part_two(devices: &Self::Parsed<'_>) -> Result<Answer> {
+        count_paths(devices, "svr", &["fft", "dac"])
+    }
+}
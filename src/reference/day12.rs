@@ -0,0 +1,166 @@
+/// Day 12: packs the presents into each region by searching all placements, after ruling out the
+/// regions that are obviously too small or obviously large enough.
+use std::collections::BTreeSet;
+
+use anyhow::{Result, bail};
+
+use super::{missing, number, numbers};
+use crate::template::{Answer, NoAnswer, Solution};
+
+pub struct Day12;
+
+/// The cells of a shape in one orientation, sorted so the first cell is the topmost, leftmost one.
+type Orientation = Vec<(i64, i64)>;
+
+pub struct Region {
+    width: usize,
+    height: usize,
+    counts: Vec<usize>,
+}
+
+/// All distinct rotations and flips of a shape.
+fn orientations(cells: &[(i64, i64)]) -> Vec<Orientation> {
+    let mut orientations = BTreeSet::new();
+    let mut cells = cells.to_vec();
+    for _ in 0..2 {
+        for _ in 0..4 {
+            cells = cells.iter().map(|&(r, c)| (c, -r)).collect();
+            let min_r = cells.iter().map(|c| c.0).min().unwrap_or(0);
+            let min_c = cells.iter().map(|c| c.1).min().unwrap_or(0);
+            let mut normalised: Orientation =
+                cells.iter().map(|&(r, c)| (r - min_r, c - min_c)).collect();
+            normalised.sort_unstable();
+            orientations.insert(normalised);
+        }
+        cells = cells.iter().map(|&(r, c)| (r, -c)).collect();
+    }
+    orientations.into_iter().collect()
+}
+
+/// Fills the region cell by cell: the first empty cell is either left empty, as long as there are
+/// `spare` cells, or covered by the first cell of a present.
+fn fits(
+    grid: &mut [Vec<bool>],
+    shapes: &[Vec<Orientation>],
+    counts: &mut [usize],
+    spare: usize,
+) -> bool {
+    if counts.iter().all(|&c| c == 0) {
+        return true;
+    }
+    let width = grid[0].len();
+    let Some((r, c)) = (0..grid.len() * width)
+        .map(|i| (i / width, i % width))
+        .find(|&(r, c)| !grid[r][c])
+    else {
+        return false;
+    };
+
+    for shape in 0..shapes.len() {
+        if counts[shape] == 0 {
+            continue;
+        }
+        for orientation in &shapes[shape] {
+            let (r0, c0) = orientation[0];
+            let cells: Option<Vec<(usize, usize)>> = orientation
+                .iter()
+                .map(|&(dr, dc)| {
+                    let (r, c) = (r as i64 + dr - r0, c as i64 + dc - c0);
+                    let cell = (usize::try_from(r).ok()?, usize::try_from(c).ok()?);
+                    (cell.0 < grid.len() && cell.1 < width && !grid[cell.0][cell.1]).then_some(cell)
+                })
+                .collect();
+            let Some(cells) = cells else { continue };
+
+            cells.iter().for_each(|&(r, c)| grid[r][c] = true);
+            counts[shape] -= 1;
+            let found = fits(grid, shapes, counts, spare);
+            counts[shape] += 1;
+            cells.iter().for_each(|&(r, c)| grid[r][c] = false);
+            if found {
+                return true;
+            }
+        }
+    }
+
+    if spare > 0 {
+        grid[r][c] = true;
+        let found = fits(grid, shapes, counts, spare - 1);
+        grid[r][c] = false;
+        return found;
+    }
+    false
+}
+
+impl Solution for Day12 {
+    type Parsed<'a> = (Vec<Vec<Orientation>>, Vec<Region>);
+    type Error = anyhow::Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let mut blocks: Vec<&str> = input.trim().split("\n\n").collect();
+        let regions = blocks.pop().ok_or_else(|| missing("regions"))?;
+
+        let shapes = blocks
+            .iter()
+            .map(|block| {
+                let cells: Vec<(i64, i64)> = block
+                    .lines()
+                    .skip(1)
+                    .enumerate()
+                    .flat_map(|(r, row)| {
+                        row.chars()
+                            .enumerate()
+                            .filter(|&(_, cell)| cell == '#')
+                            .map(move |(c, _)| (r as i64, c as i64))
+                    })
+                    .collect();
+                orientations(&cells)
+            })
+            .collect();
+
+        let regions = regions
+            .lines()
+            .map(|l| {
+                let (size, counts) = l.split_once(": ").ok_or_else(|| missing("`: `"))?;
+                let (width, height) = size.split_once('x').ok_or_else(|| missing("`x`"))?;
+                Ok(Region {
+                    width: number(width)?,
+                    height: number(height)?,
+                    counts: numbers(counts.trim(), ' ')?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok((shapes, regions))
+    }
+
+    fn part_one((shapes, regions): &Self::Parsed<'_>) -> Result<Answer> {
+        let mut fitting = 0;
+        for region in regions {
+            if region.counts.len() != shapes.len() {
+                bail!("expected {} counts per region", shapes.len());
+            }
+            let area = region.width * region.height;
+            let cells: usize = (0..shapes.len())
+                .map(|i| region.counts[i] * shapes[i][0].len())
+                .sum();
+            let presents: usize = region.counts.iter().sum();
+
+            let fits = if cells > area {
+                false
+            } else if presents <= (region.width / 3) * (region.height / 3) {
+                // Every present fits into its own 3x3 square.
+                true
+            } else {
+                let mut grid = vec![vec![false; region.width]; region.height];
+                let mut counts = region.counts.clone();
+                fits(&mut grid, shapes, &mut counts, area - cells)
+            };
+            fitting += fits as u64;
+        }
+        Ok(fitting.into())
+    }
+
+    fn part_two(_: &Self::Parsed<'_>) -> Result<Answer> {
+        Err(NoAnswer.into())
+    }
+}
@@ -0,0 +1,112 @@
+/// Slow but obviously correct solutions of each day, the reference for the optimised solutions in
+/// `src/bin`. They follow the puzzle text step by step, e.g. turning the dial of day 1 one click at
+/// a time, and are only meant for small inputs.
+///
+/// The optimised solutions are checked against them on small generated inputs with
+/// [`reference_tests!`](crate::reference_tests).
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow};
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+
+pub use day01::Day01;
+pub use day02::Day02;
+pub use day03::Day03;
+pub use day04::Day04;
+pub use day05::Day05;
+pub use day06::Day06;
+pub use day07::Day07;
+pub use day08::Day08;
+pub use day09::Day09;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+
+fn number<T: FromStr>(s: &str) -> Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let s = s.trim();
+    s.parse().with_context(|| format!("invalid number `{s}`"))
+}
+
+/// Parses all numbers of `s` separated by `sep`.
+fn numbers<T: FromStr>(s: &str, sep: char) -> Result<Vec<T>>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    s.split(sep).map(number).collect()
+}
+
+/// The non-empty lines of the input.
+fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end).filter(|l| !l.is_empty())
+}
+
+fn missing(what: &str) -> anyhow::Error {
+    anyhow!("missing {what}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::template::examples::parse_manifest;
+    use crate::template::runner::assert_example;
+    use crate::template::solution::solve;
+    use crate::template::{Day, Solution, paths};
+
+    /// Checks a reference against the examples of its day.
+    fn check<R: Solution>(day: u8) {
+        let manifest =
+            fs::read_to_string(paths::examples_manifest(Day::new(day).unwrap())).unwrap();
+        for example in parse_manifest(&manifest, day).unwrap() {
+            let params: Vec<(&str, &str)> = example
+                .params
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect();
+            assert_example::<R>(&example.file, example.part, &example.expected, &params);
+        }
+    }
+
+    #[test]
+    fn solves_examples() {
+        check::<Day01>(1);
+        check::<Day02>(2);
+        check::<Day03>(3);
+        check::<Day04>(4);
+        check::<Day05>(5);
+        check::<Day06>(6);
+        check::<Day07>(7);
+        check::<Day08>(8);
+        check::<Day09>(9);
+        check::<Day10>(10);
+        check::<Day11>(11);
+    }
+
+    /// The manifest of day 12 holds the answer of the area estimate of the solution, which is
+    /// only right for the puzzle input. The presents of the first two regions of the example do
+    /// fit, the third region takes too long to rule out in a debug build.
+    #[test]
+    fn packs_presents_of_example() {
+        let example = fs::read_to_string(paths::example(Day::new(12).unwrap())).unwrap();
+        let input = example.trim_end().rsplit_once('\n').unwrap().0;
+        assert_eq!(solve::<Day12>(input, 1).unwrap(), 2.into());
+    }
+}
@@ -0,0 +1,187 @@
+/// Allocation statistics of a solution, collected by a counting global allocator.
+///
+/// The [`solution!`](crate::solution) macro installs [`Counting`] as the global allocator of a
+/// solution binary with the `count-alloc` feature, which `cargo time --alloc` and
+/// `cargo solve --alloc` enable. The runner then reports the number of allocations, the bytes
+/// allocated and the peak heap usage of each part with [`measure`].
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::hint::black_box;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations and live bytes.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Records an allocation of `size` bytes that replaces `freed` bytes, as a reallocation does.
+/// The old and the new block may both be live for a moment, so both count towards the peak.
+fn record(size: usize, freed: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(current, Relaxed);
+    CURRENT.fetch_sub(freed, Relaxed);
+}
+
+/// Printed with the statistics, which cannot tell the threads of a solution apart.
+pub const THREADS_NOTE: &str =
+    "Allocations are counted across all threads, e.g. the rayon workers of day 10.";
+
+/// Whether [`Counting`] is the global allocator, checked once with a probe allocation.
+pub fn is_counting() -> bool {
+    static COUNTING: OnceLock<bool> = OnceLock::new();
+    *COUNTING.get_or_init(|| {
+        let before = ALLOCATIONS.load(Relaxed);
+        drop(black_box(Box::new(0_u64)));
+        ALLOCATIONS.load(Relaxed) > before
+    })
+}
+
+/// Allocations made while running a function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub allocations: usize,
+    /// Sum of the sizes of all allocations, including reallocations.
+    pub bytes: usize,
+    /// Largest amount of memory live at once, on top of what was live before.
+    pub peak: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Runs `f` and counts its allocations, `None` if [`Counting`] is not the global allocator.
+/// Allocations of other threads running at the same time are counted too, e.g. the rayon workers
+/// of a parallel solution.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    if !is_counting() {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let bytes = BYTES.load(Relaxed);
+    let current = CURRENT.load(Relaxed);
+    PEAK.store(current, Relaxed);
+
+    let result = f();
+
+    let stats = Stats {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(current),
+    };
+    (result, Some(stats))
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+    use std::sync::atomic::Ordering::Relaxed;
+
+    use super::{ALLOCATIONS, BYTES, CURRENT, Counting, Stats, format_bytes, measure};
+
+    #[test]
+    fn counts_allocations() {
+        // Only this test allocates with `Counting`, the tests run with the system allocator.
+        let (small, large) = (
+            Layout::from_size_align(64, 8).unwrap(),
+            Layout::from_size_align(128, 8).unwrap(),
+        );
+        let before = (ALLOCATIONS.load(Relaxed), BYTES.load(Relaxed));
+        unsafe {
+            let ptr = Counting.alloc(small);
+            let ptr = Counting.realloc(ptr, small, large.size());
+            assert_eq!(CURRENT.load(Relaxed), 128);
+            Counting.dealloc(ptr, large);
+        }
+        assert_eq!(ALLOCATIONS.load(Relaxed) - before.0, 2);
+        assert_eq!(BYTES.load(Relaxed) - before.1, 192);
+        assert_eq!(CURRENT.load(Relaxed), 0);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = Stats {
+            allocations: 12,
+            bytes: 4096,
+            peak: 100,
+        };
+        assert_eq!(stats.to_string(), "12 allocs, 4.0 KiB, peak 100 B");
+    }
+
+    #[test]
+    fn skips_measuring_without_counting_allocator() {
+        // Tests run with the system allocator.
+        let (len, stats) = measure(|| vec![1, 2, 3].len());
+        assert_eq!((len, stats), (3, None));
+    }
+}
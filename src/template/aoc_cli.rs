@@ -1,16 +1,20 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    path::Path,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::config::Config;
+use crate::template::inputs::{self, InputError};
+use crate::template::{Day, paths};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
+    InputStorage(InputError),
 }
 
 impl Display for AocCommandError {
@@ -21,6 +25,7 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::InputStorage(e) => write!(f, "could not store input: {e}"),
         }
     }
 }
@@ -34,7 +39,7 @@ pub fn check() -> Result<(), AocCommandError> {
 }
 
 pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+    let puzzle_path = paths::puzzle(day);
 
     let args = build_args(
         "read",
@@ -50,8 +55,8 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
 }
 
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+    let input_path = paths::input(day);
+    let puzzle_path = paths::puzzle(day);
 
     let args = build_args(
         "download",
@@ -66,8 +71,13 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     );
 
     let output = call_aoc_cli(&args)?;
+    let stored_path =
+        inputs::store(Path::new(&input_path)).map_err(AocCommandError::InputStorage)?;
     println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        stored_path.display()
+    );
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
     Ok(output)
 }
@@ -80,19 +90,8 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     call_aoc_cli(&args)
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
-
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+pub fn get_year() -> Option<u16> {
+    Config::get().year
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
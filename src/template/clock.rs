@@ -0,0 +1,280 @@
+/// Time keeping for the `today` workflow.
+/// Wall-clock access goes through the [`Clock`] trait so the puzzle schedule can be tested with a [`FakeClock`].
+use std::cell::Cell;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::{Day, aoc_cli};
+
+/// Puzzles unlock at midnight EST, the server does not observe daylight saving time in december.
+const SERVER_UTC_OFFSET: i64 = -5;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A source of the current time.
+pub trait Clock {
+    /// The current time as seconds since the unix epoch.
+    fn now(&self) -> i64;
+
+    /// Blocks the current thread for the provided duration.
+    fn sleep(&self, duration: Duration);
+}
+
+/// The clock of the operating system.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> i64 {
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is before the unix epoch");
+        i64::try_from(elapsed.as_secs()).unwrap_or(i64::MAX)
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock that only advances when it is slept on.
+pub struct FakeClock {
+    now: Cell<i64>,
+}
+
+impl FakeClock {
+    /// Creates a clock set to `now` seconds since the unix epoch.
+    pub fn new(now: i64) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    /// Creates a clock set to the provided UTC date and time.
+    pub fn at_utc(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Self {
+        let timestamp = days_from_civil(year, month, day) * SECONDS_PER_DAY
+            + i64::from(hour * 3600 + minute * 60 + second);
+        Self::new(timestamp)
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> i64 {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        let secs = i64::try_from(duration.as_secs()).unwrap_or(i64::MAX);
+        self.now.set(self.now.get().saturating_add(secs));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Number of days since the unix epoch for a date of the proleptic gregorian calendar.
+/// See: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date of the proleptic gregorian calendar as `(year, month, day)` for a number of days since the unix epoch.
+/// See: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (year as i32, month as u32, day as u32)
+}
+
+/// The current date in the timezone of the advent of code server.
+fn server_date(clock: &impl Clock) -> (i32, u32, u32) {
+    let local = clock.now() + SERVER_UTC_OFFSET * 3600;
+    civil_from_days(local.div_euclid(SECONDS_PER_DAY))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The year of the event: `AOC_YEAR` if set, the current year on the server otherwise.
+pub fn event_year(clock: &impl Clock) -> i32 {
+    aoc_cli::get_year().map_or_else(|| server_date(clock).0, i32::from)
+}
+
+/// Number of puzzles of an event. Starting with 2025, events run for 12 days.
+pub fn event_length(year: i32) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Unix timestamp at which the puzzle of `day` unlocks in `year`.
+pub fn unlock_time(year: i32, day: Day) -> i64 {
+    days_from_civil(year, 12, u32::from(day.into_inner())) * SECONDS_PER_DAY
+        - SERVER_UTC_OFFSET * 3600
+}
+
+/// Returns the day of the puzzle released today in the event of `year`, `None` if no puzzle of
+/// that event was released today.
+pub fn today(clock: &impl Clock, year: i32) -> Option<Day> {
+    let (server_year, month, day) = server_date(clock);
+    if server_year != year || month != 12 {
+        return None;
+    }
+    let day = Day::new(u8::try_from(day).ok()?)?;
+    (day <= event_length(year)).then_some(day)
+}
+
+/// Returns the next puzzle of the event of `year` that is still locked, together with its unlock
+/// time. Returns `None` once every puzzle of the event was released.
+pub fn next_unlock(clock: &impl Clock, year: i32) -> Option<(Day, i64)> {
+    let now = clock.now();
+    (1..=event_length(year))
+        .filter_map(Day::new)
+        .map(|day| (day, unlock_time(year, day)))
+        .find(|(_, unlock)| *unlock > now)
+}
+
+/// Blocks until `unlock`, calling `on_tick` with the remaining seconds about once per second.
+pub fn wait_until(clock: &impl Clock, unlock: i64, mut on_tick: impl FnMut(i64)) {
+    loop {
+        let remaining = unlock - clock.now();
+        if remaining <= 0 {
+            break;
+        }
+        on_tick(remaining);
+        #[allow(clippy::cast_sign_loss)]
+        clock.sleep(Duration::from_secs(remaining.min(1) as u64));
+    }
+}
+
+/// Formats a number of seconds as a countdown, e.g. `2d 03:04:05`.
+pub fn format_countdown(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (days, rest) = (seconds / SECONDS_PER_DAY, seconds % SECONDS_PER_DAY);
+    let (hours, minutes, seconds) = (rest / 3600, rest % 3600 / 60, rest % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Formats a unix timestamp as UTC date and time, e.g. `2025-12-01 05:00 UTC`.
+pub fn format_utc(timestamp: i64) -> String {
+    let (year, month, day) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+    let rest = timestamp.rem_euclid(SECONDS_PER_DAY);
+    format!(
+        "{year}-{month:02}-{day:02} {:02}:{:02} UTC",
+        rest / 3600,
+        rest % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        FakeClock, civil_from_days, days_from_civil, format_countdown, format_utc, next_unlock,
+        today, unlock_time, wait_until,
+    };
+    use crate::day;
+
+    #[test]
+    fn converts_civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2025, 12, 1), 20_423);
+        assert_eq!(civil_from_days(20_423), (2025, 12, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        assert_eq!(
+            format_utc(unlock_time(2025, day!(1))),
+            "2025-12-01 05:00 UTC"
+        );
+        assert_eq!(
+            format_utc(unlock_time(2025, day!(12))),
+            "2025-12-12 05:00 UTC"
+        );
+    }
+
+    #[test]
+    fn finds_today_during_event() {
+        assert_eq!(
+            today(&FakeClock::at_utc(2025, 12, 5, 5, 0, 0), 2025),
+            Some(day!(5))
+        );
+        assert_eq!(
+            today(&FakeClock::at_utc(2025, 12, 6, 4, 59, 59), 2025),
+            Some(day!(5))
+        );
+        assert_eq!(
+            today(&FakeClock::at_utc(2024, 12, 25, 12, 0, 0), 2024),
+            Some(day!(25))
+        );
+    }
+
+    #[test]
+    fn finds_no_day_outside_event() {
+        assert_eq!(
+            today(&FakeClock::at_utc(2025, 12, 1, 4, 59, 59), 2025),
+            None
+        );
+        assert_eq!(
+            today(&FakeClock::at_utc(2025, 12, 13, 12, 0, 0), 2025),
+            None
+        );
+        assert_eq!(today(&FakeClock::at_utc(2025, 7, 4, 12, 0, 0), 2025), None);
+        assert_eq!(today(&FakeClock::at_utc(2026, 12, 4, 12, 0, 0), 2025), None);
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        let clock = FakeClock::at_utc(2025, 11, 30, 12, 0, 0);
+        assert_eq!(
+            next_unlock(&clock, 2025),
+            Some((day!(1), unlock_time(2025, day!(1))))
+        );
+
+        let clock = FakeClock::at_utc(2025, 12, 5, 5, 0, 0);
+        assert_eq!(
+            next_unlock(&clock, 2025),
+            Some((day!(6), unlock_time(2025, day!(6))))
+        );
+
+        let clock = FakeClock::at_utc(2025, 12, 12, 5, 0, 0);
+        assert_eq!(next_unlock(&clock, 2025), None);
+    }
+
+    #[test]
+    fn waits_until_unlock() {
+        let clock = FakeClock::at_utc(2025, 12, 1, 4, 59, 57);
+        let mut ticks = vec![];
+        wait_until(&clock, unlock_time(2025, day!(1)), |remaining| {
+            ticks.push(remaining)
+        });
+        assert_eq!(ticks, vec![3, 2, 1]);
+        assert_eq!(today(&clock, 2025), Some(day!(1)));
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(59), "00:00:59");
+        assert_eq!(format_countdown(3 * 86_400 + 3661), "3d 01:01:01");
+        assert_eq!(format_countdown(-5), "00:00:00");
+    }
+}
@@ -0,0 +1,123 @@
+use std::{fs, path::Path, process};
+
+use crate::template::timings::Timings;
+use crate::template::{Day, bins, inputs, paths, readme_benchmarks};
+
+pub fn handle(day: Day, dry_run: bool) {
+    let files = artefacts(day);
+    let stored_timings = Timings::read_from_file();
+    let has_timing = stored_timings.contains(day);
+
+    if files.is_empty() && !has_timing {
+        println!("Nothing to clean for day {day}.");
+        return;
+    }
+
+    if dry_run {
+        println!("The following artefacts of day {day} would be removed:");
+    } else {
+        println!("Removing artefacts of day {day}:");
+    }
+
+    for file in &files {
+        println!("  {file}");
+        if !dry_run && let Err(e) = fs::remove_file(file) {
+            eprintln!("Failed to remove \"{file}\": {e}");
+            process::exit(1);
+        }
+    }
+
+    // The corpus folder is empty once its inputs and answers are removed.
+    if !dry_run {
+        let _ = fs::remove_dir(paths::corpus(day));
+    }
+
+    if has_timing {
+        println!("  timing entry in {}", paths::timings());
+        if !dry_run {
+            remove_timing(&stored_timings, day);
+        }
+    }
+
+    println!("---");
+    if dry_run {
+        println!(
+            "🎄 This was a dry run. Type `cargo reset {day} --confirm` to remove these artefacts."
+        );
+    } else {
+        println!("🎄 Cleaned day {day}. Type `cargo scaffold {day}` to start over.");
+    }
+}
+
+/// Existing files generated for a day: solution binaries, examples and their manifest,
+/// (encrypted) input, puzzle and the regression corpus with its stored answers.
+fn artefacts(day: Day) -> Vec<String> {
+    let mut files: Vec<String> = bins(day).iter().map(paths::bin).collect();
+    files.extend(example_files(day));
+    files.push(paths::examples_manifest(day));
+    files.push(paths::input(day));
+    files.push(
+        inputs::encrypted_path(Path::new(&paths::input(day)))
+            .display()
+            .to_string(),
+    );
+    files.push(paths::puzzle(day));
+    files.extend(corpus_files(day));
+    files.retain(|file| Path::new(file).is_file());
+    files
+}
+
+/// Example files of a day, i.e. `NN.txt` and part specific examples like `NN-2.txt`.
+fn example_files(day: Day) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(paths::data_dir("examples")) else {
+        return vec![];
+    };
+
+    let prefix = day.to_string();
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name.strip_suffix(".txt")
+                .and_then(|stem| stem.strip_prefix(&prefix))
+                .is_some_and(|suffix| {
+                    suffix.is_empty()
+                        || suffix
+                            .strip_prefix('-')
+                            .is_some_and(|part| part.parse::<u8>().is_ok())
+                })
+        })
+        .map(|name| format!("{}/{name}", paths::data_dir("examples")))
+        .collect();
+
+    files.sort_unstable();
+    files
+}
+
+/// Files of the regression corpus of a day, i.e. its inputs and the `answers.toml` storing their
+/// expected answers, see [`crate::template::corpus`].
+fn corpus_files(day: Day) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(paths::corpus(day)) else {
+        return vec![];
+    };
+
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .map(|name| format!("{}/{name}", paths::corpus(day)))
+        .collect();
+
+    files.sort_unstable();
+    files
+}
+
+fn remove_timing(stored_timings: &Timings, day: Day) {
+    let timings = stored_timings.remove(day);
+
+    if let Err(e) = timings.store_file() {
+        eprintln!("Failed to store timings: {e}");
+        process::exit(1);
+    }
+
+    if readme_benchmarks::update(timings).is_err() {
+        eprintln!("Failed to update the benchmarks in the readme.");
+    }
+}
@@ -0,0 +1,131 @@
+use std::process;
+
+use crate::template::run_multi::child_commands::{parse_answers, parse_exec_time, run_solution};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, bins};
+
+struct Outcome {
+    name: String,
+    answers: [Option<String>; 2],
+    timings: [Option<String>; 2],
+}
+
+pub fn handle(day: Day) {
+    let bins = bins(day);
+
+    if bins.is_empty() {
+        eprintln!("Day {day} has not been scaffolded yet.");
+        process::exit(1);
+    }
+
+    let mut outcomes = Vec::with_capacity(bins.len());
+
+    for (i, bin) in bins.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}{bin}{ANSI_RESET}");
+        println!("------");
+
+        let output = match run_solution(bin, true, true, false) {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Failed to run {bin}: {e:?}");
+                process::exit(1);
+            }
+        };
+
+        let timing = parse_exec_time(&output, day);
+        outcomes.push(Outcome {
+            name: bin.to_string(),
+            answers: parse_answers(&output),
+            timings: [timing.part_1, timing.part_2],
+        });
+    }
+
+    println!();
+    print_table(&outcomes);
+    println!();
+
+    let mut agree = true;
+    for part in 0..2 {
+        agree &= print_agreement(&outcomes, part);
+    }
+
+    if !agree {
+        process::exit(1);
+    }
+}
+
+fn format_cell(answer: Option<&String>, timing: Option<&String>) -> String {
+    match (answer, timing) {
+        (Some(answer), Some(timing)) => format!("{answer} ({timing})"),
+        (Some(answer), None) => answer.clone(),
+        (None, _) => "✖".into(),
+    }
+}
+
+fn print_table(outcomes: &[Outcome]) {
+    let rows: Vec<[String; 3]> = outcomes
+        .iter()
+        .map(|o| {
+            [
+                o.name.clone(),
+                format_cell(o.answers[0].as_ref(), o.timings[0].as_ref()),
+                format_cell(o.answers[1].as_ref(), o.timings[1].as_ref()),
+            ]
+        })
+        .collect();
+
+    let header = ["Variant".to_string(), "Part 1".into(), "Part 2".into()];
+    let widths: Vec<usize> = (0..3)
+        .map(|c| {
+            rows.iter()
+                .chain([&header])
+                .map(|r| r[c].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in [&header].into_iter().chain(rows.iter()) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    }
+}
+
+/// Print whether all variants that produced an answer for `part` agree.
+/// Returns `false` if at least two answers differ.
+fn print_agreement(outcomes: &[Outcome], part: usize) -> bool {
+    let answered: Vec<(&str, &str)> = outcomes
+        .iter()
+        .filter_map(|o| Some((o.name.as_str(), o.answers[part].as_deref()?)))
+        .collect();
+
+    let part_str = format!("Part {}", part + 1);
+
+    match answered.first() {
+        None => {
+            println!("{part_str}: {ANSI_ITALIC}no answers{ANSI_RESET}");
+            true
+        }
+        Some((_, expected)) if answered.iter().all(|(_, a)| a == expected) => {
+            println!(
+                "{part_str}: ✔ {} variant(s) agree on {ANSI_BOLD}{expected}{ANSI_RESET}",
+                answered.len()
+            );
+            true
+        }
+        Some(_) => {
+            println!("{part_str}: ✖ variants disagree");
+            for (name, answer) in answered {
+                println!("  {name}: {answer}");
+            }
+            false
+        }
+    }
+}
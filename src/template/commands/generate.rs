@@ -0,0 +1,27 @@
+use std::io::{self, Write};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::{Day, generators};
+
+/// Prints a random input for a day to stdout, e.g. to pipe it into `cargo solve NN -`.
+/// Without a seed a random one is used and reported on stderr, so the input can be reproduced.
+pub fn handle(day: Day, size: Option<usize>, seed: Option<u64>) {
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        eprintln!("Using seed {seed}.");
+        seed
+    });
+
+    let Some(input) = generators::generate(day, size, seed) else {
+        eprintln!("There is no input generator for day {day}.");
+        process::exit(1);
+    };
+
+    if let Err(e) = io::stdout().write_all(input.as_bytes()) {
+        eprintln!("Failed to write the input: {e}");
+        process::exit(1);
+    }
+}
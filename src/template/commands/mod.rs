@@ -1,6 +1,11 @@
 pub mod all;
+pub mod clean;
+pub mod compare;
 pub mod download;
+pub mod generate;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod today;
+pub mod verify;
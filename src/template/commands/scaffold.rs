@@ -4,11 +4,22 @@ use std::{
     process,
 };
 
-use crate::template::Day;
+use crate::template::{Bin, Day, paths};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+const EXAMPLES_TEMPLATE: &str = r#"# Uncomment to test a part against the example, see `src/template/examples.rs`.
+
+# [[example]]
+# part = 1
+# expected = 0
+
+# [[example]]
+# part = 2
+# expected = 0
+"#;
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -27,10 +38,11 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(day: Day, overwrite: bool, variant: Option<&str>) {
+    let input_path = paths::input(day);
+    let example_path = paths::example(day);
+    let manifest_path = paths::examples_manifest(day);
+    let module_path = paths::bin(&Bin::new(day, variant));
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -54,6 +66,13 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    // variants share the input and example files of the main solution.
+    if let Some(variant) = variant {
+        println!("---");
+        println!("🎄 Type `cargo solve {day} --variant {variant}` to run your solution.");
+        return;
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -74,6 +93,20 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match safe_create_file(&manifest_path, overwrite) {
+        Ok(mut file) => match file.write_all(EXAMPLES_TEMPLATE.as_bytes()) {
+            Ok(()) => println!("Created examples manifest \"{}\"", &manifest_path),
+            Err(e) => {
+                eprintln!("Failed to write examples manifest: {e}");
+                process::exit(1);
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to create examples manifest: {e}");
+            process::exit(1);
+        }
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
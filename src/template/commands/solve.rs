@@ -1,11 +1,32 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::runner::InputSource;
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Bin, Day, alloc};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How the heap of the solution is observed, selected by `--dhat` or `--alloc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heap {
+    /// The system allocator, nothing is reported.
+    System,
+    /// Counts the allocations of each part, see [`alloc`].
+    Counting,
+    /// Profiles the heap with dhat, in the `dhat` profile.
+    Dhat,
+}
+
+pub fn handle(
+    day: Day,
+    release: bool,
+    heap: Heap,
+    submit_part: Option<u8>,
+    variant: Option<&str>,
+    input: &InputSource,
+    params: &[String],
+) {
+    let bin = Bin::new(day, variant);
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), bin.to_string()];
 
-    if dhat {
+    if heap == Heap::Dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
@@ -16,6 +37,10 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push("--release".to_string());
     }
 
+    if heap == Heap::Counting {
+        cmd_args.extend(["--features".to_string(), "count-alloc".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,6 +48,13 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    cmd_args.extend(input.to_args());
+
+    for param in params {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(param.clone());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -31,4 +63,8 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .unwrap();
 
     cmd.wait().unwrap();
+
+    if heap == Heap::Counting {
+        println!("{ANSI_ITALIC}{}{ANSI_RESET}", alloc::THREADS_NOTE);
+    }
 }
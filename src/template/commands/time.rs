@@ -2,9 +2,11 @@ use std::collections::HashSet;
 
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, all_days, alloc, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Times the days and stores the timings if `store` is set. With `alloc`, the days are built with
+/// the counting allocator and the allocation statistics of the parts are included.
+pub fn handle(day: Option<Day>, run_all: bool, store: bool, alloc: bool) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
@@ -21,7 +23,10 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&days_to_run, true, true, alloc).unwrap();
+    if alloc {
+        println!("{ANSI_ITALIC}{}{ANSI_RESET}", alloc::THREADS_NOTE);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
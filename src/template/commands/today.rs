@@ -0,0 +1,52 @@
+use std::io::{Write, stdout};
+use std::process;
+
+use crate::template::clock::{self, Clock, SystemClock};
+use crate::template::commands::{download, read, scaffold};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+pub fn handle(wait: bool) {
+    let clock = SystemClock;
+    let year = clock::event_year(&clock);
+
+    if !wait && let Some(day) = Day::today_with(&clock) {
+        setup(day);
+        return;
+    }
+
+    let Some((day, unlock)) = clock::next_unlock(&clock, year) else {
+        eprintln!(
+            "All puzzles of Advent of Code {year} have been released. \
+            Please use `scaffold` with a specific day."
+        );
+        process::exit(1);
+    };
+
+    if !wait {
+        println!(
+            "No puzzle was released today. Day {day} of {year} unlocks in {} ({}).",
+            clock::format_countdown(unlock - clock.now()),
+            clock::format_utc(unlock)
+        );
+        println!("🎄 Type `cargo today --wait` to scaffold it as soon as it unlocks.");
+        return;
+    }
+
+    let mut stdout = stdout();
+    clock::wait_until(&clock, unlock, |remaining| {
+        print!(
+            "\r⏳ Day {day} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            clock::format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+    });
+    println!("\r🔓 Day {day} is unlocked!                    ");
+
+    setup(day);
+}
+
+fn setup(day: Day) {
+    scaffold::handle(day, false, None);
+    download::handle(day);
+    read::handle(day);
+}
@@ -0,0 +1,48 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET, Bin, Day, corpus};
+
+/// Checks the solutions of one or all days against their regression corpus.
+pub fn handle(day: Option<Day>) {
+    let days = day.map_or_else(corpus::days, |day| vec![day]);
+
+    if days.is_empty() {
+        println!("No corpus found, add inputs to e.g. \"data/inputs/01/\" to verify a day.");
+        return;
+    }
+
+    let mut failed = vec![];
+
+    for (i, day) in days.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let bin = Bin::main(*day);
+        if !bin.exists() {
+            println!("Not solved.");
+            continue;
+        }
+
+        let status = Command::new("cargo")
+            .args(["run", "--quiet", "--release", "--bin", &bin.to_string()])
+            .args(["--", "--corpus"])
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status();
+
+        if !status.is_ok_and(|status| status.success()) {
+            failed.push(day.to_string());
+        }
+    }
+
+    println!();
+    if failed.is_empty() {
+        println!("🎄 Verified {} day(s).", days.len());
+    } else {
+        println!("❌ Verification failed for day(s) {}.", failed.join(", "));
+        process::exit(1);
+    }
+}
@@ -0,0 +1,291 @@
+/// Project configuration: the year of the event and where the data files live.
+///
+/// The configuration is read from an `aoc.toml` file in the project root or, if that file does
+/// not exist, from the `[package.metadata.aoc]` table of `Cargo.toml`. All keys are optional:
+///
+/// ```toml
+/// year = 2025
+/// data_dir = "data"                      # root of the folders below
+/// inputs_dir = "~/.config/aoc/2025"      # defaults to `<data_dir>/inputs`
+/// examples_dir = "data/examples"         # defaults to `<data_dir>/examples`
+/// puzzles_dir = "data/puzzles"           # defaults to `<data_dir>/puzzles`
+/// readme = "README.md"
+/// input_storage = "encrypted"            # `plain` (default) or `encrypted`
+/// input_key_file = "~/.config/aoc/key"   # used if `AOC_INPUT_KEY` is not set
+/// ```
+///
+/// Relative paths are resolved against the project root and `~/` expands to the home directory.
+/// The `AOC_YEAR` environment variable takes precedence over the configured year.
+/// This module only depends on `std` and [`super::toml`] so it can be shared with the build script.
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::{env, fmt::Display, fs, io};
+
+use super::toml::{self, Table, TomlError};
+
+const CONFIG_FILE: &str = "aoc.toml";
+const MANIFEST_FILE: &str = "Cargo.toml";
+const MANIFEST_TABLE: &str = "package.metadata.aoc";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub year: Option<u16>,
+    pub data_dir: PathBuf,
+    pub inputs_dir: PathBuf,
+    pub examples_dir: PathBuf,
+    pub puzzles_dir: PathBuf,
+    pub readme: PathBuf,
+    pub input_storage: InputStorage,
+    pub input_key_file: Option<PathBuf>,
+}
+
+/// How downloaded puzzle inputs are written to the inputs directory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputStorage {
+    /// As plain text files, e.g. `01.txt`.
+    #[default]
+    Plain,
+    /// Encrypted with the input key, e.g. `01.txt.enc`.
+    Encrypted,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let data_dir = PathBuf::from("data");
+        Self {
+            year: None,
+            inputs_dir: data_dir.join("inputs"),
+            examples_dir: data_dir.join("examples"),
+            puzzles_dir: data_dir.join("puzzles"),
+            data_dir,
+            readme: PathBuf::from("README.md"),
+            input_storage: InputStorage::default(),
+            input_key_file: None,
+        }
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+impl Config {
+    /// The configuration of the project in the current working directory, loaded once.
+    ///
+    /// # Panics
+    /// Panics if a configuration file exists but is invalid.
+    pub fn get() -> &'static Config {
+        CONFIG.get_or_init(|| {
+            let mut config = Config::load(Path::new("."))
+                .unwrap_or_else(|e| panic!("could not load configuration: {e}"));
+            if let Some(year) = env::var("AOC_YEAR").ok().and_then(|y| y.parse().ok()) {
+                config.year = Some(year);
+            }
+            config
+        })
+    }
+
+    /// Loads the configuration of the project located at `root`, relative paths are resolved
+    /// against it.
+    pub fn load(root: &Path) -> Result<Self, ConfigError> {
+        Ok(Self::load_table(root)?.relative_to(root))
+    }
+
+    fn load_table(root: &Path) -> Result<Self, ConfigError> {
+        let config_path = root.join(CONFIG_FILE);
+        if config_path.is_file() {
+            let table = read_toml(&config_path)?;
+            return Self::from_table(&table);
+        }
+
+        let manifest_path = root.join(MANIFEST_FILE);
+        if manifest_path.is_file() {
+            let manifest = read_toml(&manifest_path)?;
+            if let Some(value) = toml::get(&manifest, MANIFEST_TABLE) {
+                let table = value.as_table().ok_or_else(|| {
+                    ConfigError::Invalid(format!("`{MANIFEST_TABLE}` is not a table"))
+                })?;
+                return Self::from_table(table);
+            }
+        }
+
+        Ok(Self::default())
+    }
+
+    /// The configuration with relative paths joined to `root`, absolute paths are kept.
+    fn relative_to(mut self, root: &Path) -> Self {
+        let paths = [
+            &mut self.data_dir,
+            &mut self.inputs_dir,
+            &mut self.examples_dir,
+            &mut self.puzzles_dir,
+            &mut self.readme,
+        ];
+        for path in paths.into_iter().chain(&mut self.input_key_file) {
+            *path = root.join(&path);
+        }
+        self
+    }
+
+    /// Builds a configuration from the keys of `table`, using defaults for missing keys.
+    pub fn from_table(table: &Table) -> Result<Self, ConfigError> {
+        let get_path = |key: &str| -> Result<Option<PathBuf>, ConfigError> {
+            table
+                .get(key)
+                .map(|value| {
+                    value.as_str().map(expand_home).ok_or_else(|| {
+                        ConfigError::Invalid(format!("`{key}` is expected to be a string"))
+                    })
+                })
+                .transpose()
+        };
+
+        let year = table
+            .get("year")
+            .map(|value| {
+                value
+                    .as_integer()
+                    .and_then(|y| u16::try_from(y).ok())
+                    .ok_or_else(|| ConfigError::Invalid("`year` is expected to be a year".into()))
+            })
+            .transpose()?;
+
+        let input_storage = match table.get("input_storage").map(|value| value.as_str()) {
+            None => InputStorage::default(),
+            Some(Some("plain")) => InputStorage::Plain,
+            Some(Some("encrypted")) => InputStorage::Encrypted,
+            Some(_) => {
+                return Err(ConfigError::Invalid(
+                    "`input_storage` is expected to be \"plain\" or \"encrypted\"".into(),
+                ));
+            }
+        };
+
+        let defaults = Self::default();
+        let data_dir = get_path("data_dir")?.unwrap_or(defaults.data_dir);
+
+        Ok(Self {
+            year,
+            inputs_dir: get_path("inputs_dir")?.unwrap_or_else(|| data_dir.join("inputs")),
+            examples_dir: get_path("examples_dir")?.unwrap_or_else(|| data_dir.join("examples")),
+            puzzles_dir: get_path("puzzles_dir")?.unwrap_or_else(|| data_dir.join("puzzles")),
+            readme: get_path("readme")?.unwrap_or(defaults.readme),
+            input_storage,
+            input_key_file: get_path("input_key_file")?,
+            data_dir,
+        })
+    }
+
+    /// Directory of a data folder. The `inputs`, `examples` and `puzzles` folders map to their
+    /// configured location, any other folder is looked up in the data directory.
+    pub fn folder(&self, folder: &str) -> PathBuf {
+        match folder {
+            "inputs" => self.inputs_dir.clone(),
+            "examples" => self.examples_dir.clone(),
+            "puzzles" => self.puzzles_dir.clone(),
+            folder => self.data_dir.join(folder),
+        }
+    }
+}
+
+fn read_toml(path: &Path) -> Result<Table, ConfigError> {
+    let content = fs::read_to_string(path).map_err(ConfigError::IO)?;
+    toml::parse(&content).map_err(|e| ConfigError::Toml(path.to_path_buf(), e))
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// An error which can be returned when loading the [`Config`].
+#[derive(Debug)]
+pub enum ConfigError {
+    IO(io::Error),
+    Toml(PathBuf, TomlError),
+    Invalid(String),
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IO(e) => write!(f, "{e}"),
+            ConfigError::Toml(path, e) => write!(f, "{}: {e}", path.display()),
+            ConfigError::Invalid(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Config, InputStorage, toml};
+    use std::path::PathBuf;
+    use std::{env, fs, process};
+
+    #[test]
+    fn uses_defaults_for_empty_table() {
+        let config = Config::from_table(&toml::Table::new()).unwrap();
+        assert_eq!(config, Config::default());
+        assert_eq!(config.inputs_dir, PathBuf::from("data/inputs"));
+    }
+
+    #[test]
+    fn derives_folders_from_data_dir() {
+        let table = toml::parse("year = 2024\ndata_dir = \"../aoc-data\"").unwrap();
+        let config = Config::from_table(&table).unwrap();
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.inputs_dir, PathBuf::from("../aoc-data/inputs"));
+        assert_eq!(
+            config.folder("examples"),
+            PathBuf::from("../aoc-data/examples")
+        );
+        assert_eq!(
+            config.folder("answers"),
+            PathBuf::from("../aoc-data/answers")
+        );
+    }
+
+    #[test]
+    fn overrides_single_folders() {
+        let table = toml::parse("inputs_dir = \"/secret/inputs\"").unwrap();
+        let config = Config::from_table(&table).unwrap();
+        assert_eq!(config.inputs_dir, PathBuf::from("/secret/inputs"));
+        assert_eq!(config.examples_dir, PathBuf::from("data/examples"));
+    }
+
+    #[test]
+    fn reads_input_storage() {
+        let table =
+            toml::parse("input_storage = \"encrypted\"\ninput_key_file = \"/keys/aoc\"").unwrap();
+        let config = Config::from_table(&table).unwrap();
+        assert_eq!(config.input_storage, InputStorage::Encrypted);
+        assert_eq!(config.input_key_file, Some(PathBuf::from("/keys/aoc")));
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        assert!(Config::from_table(&toml::parse("year = \"soon\"").unwrap()).is_err());
+        assert!(Config::from_table(&toml::parse("inputs_dir = 1").unwrap()).is_err());
+        assert!(Config::from_table(&toml::parse("input_storage = \"zip\"").unwrap()).is_err());
+    }
+
+    #[test]
+    fn resolves_paths_against_the_root() {
+        let root = env::temp_dir().join(format!("aoc-config-{}", process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let manifest = "[package]\nname = \"aoc\"\nkeywords = [\n  \"aoc\",\n]\n\n\
+                        [package.metadata.aoc]\ndata_dir = \"data\"\ninputs_dir = \"/secret/inputs\"\n";
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+        let config = Config::load(&root);
+        fs::remove_dir_all(&root).unwrap();
+
+        let config = config.unwrap();
+        assert_eq!(config.data_dir, root.join("data"));
+        assert_eq!(config.examples_dir, root.join("data/examples"));
+        assert_eq!(config.inputs_dir, PathBuf::from("/secret/inputs"));
+    }
+}
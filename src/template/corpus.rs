@@ -0,0 +1,160 @@
+/// A regression corpus of additional puzzle inputs per day, e.g. the inputs of teammates.
+///
+/// The corpus of a day lives in a folder of the inputs directory, e.g. `data/inputs/11/`, holding
+/// any number of (optionally encrypted) `*.txt` inputs. Expected answers are listed per input in
+/// an `answers.toml` next to them, keyed by the file stem:
+///
+/// ```toml
+/// [alice]
+/// part_one = 1034
+/// part_two = "384562180131232"
+/// ```
+///
+/// Answers can be given as integers or strings; answers that do not fit an `i64` must be strings.
+///
+/// Puzzle inputs must not be committed in plain text, so git only tracks the answers and the
+/// [encrypted](crate::template::inputs) inputs of a corpus:
+///
+/// ```text
+/// data/inputs/11/
+/// ├── answers.toml     tracked
+/// ├── alice.txt.enc    tracked
+/// └── bob.txt          ignored
+/// ```
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::inputs::{self, ENCRYPTED_EXTENSION};
+use crate::template::toml::{self, TomlError};
+use crate::template::{Day, all_days, paths};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// An input of the corpus together with its expected answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: [Option<String>; 2],
+}
+
+impl Entry {
+    pub fn read(&self) -> Result<String, inputs::InputError> {
+        inputs::read(&self.path)
+    }
+}
+
+/// The outcome of running a part against a corpus entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+    Failed,
+}
+
+impl Verdict {
+    pub fn new(answer: Option<&str>, expected: Option<&str>) -> Self {
+        match (answer, expected) {
+            (None, _) => Verdict::Failed,
+            (Some(_), None) => Verdict::Unknown,
+            (Some(answer), Some(expected)) if answer == expected => Verdict::Correct,
+            (Some(_), Some(expected)) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Wrong { .. } | Verdict::Failed)
+    }
+}
+
+/// Days that have a corpus.
+pub fn days() -> Vec<Day> {
+    all_days()
+        .filter(|day| Path::new(&paths::corpus(*day)).is_dir())
+        .collect()
+}
+
+/// Loads the corpus of a day, sorted by input name. Returns an empty corpus if the day has none.
+pub fn load(day: Day) -> Result<Vec<Entry>, CorpusError> {
+    let dir = PathBuf::from(paths::corpus(day));
+    let Ok(dir_entries) = fs::read_dir(&dir) else {
+        return Ok(vec![]);
+    };
+
+    let answers_path = dir.join(ANSWERS_FILE);
+    let answers = if answers_path.is_file() {
+        let content = fs::read_to_string(&answers_path).map_err(CorpusError::IO)?;
+        toml::parse(&content).map_err(|e| CorpusError::Toml(answers_path, e))?
+    } else {
+        toml::Table::new()
+    };
+
+    let encrypted_suffix = format!(".txt.{ENCRYPTED_EXTENSION}");
+    let mut entries: Vec<Entry> = dir_entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|file_name| {
+            let name = file_name
+                .strip_suffix(".txt")
+                .or_else(|| file_name.strip_suffix(&encrypted_suffix))?;
+            let expected = ["part_one", "part_two"]
+                .map(|part| toml::get(&answers, &format!("{name}.{part}")).map(toml::plain));
+            Some(Entry {
+                name: name.to_string(),
+                path: dir.join(format!("{name}.txt")),
+                expected,
+            })
+        })
+        .collect();
+
+    // an input may be stored both encrypted and as plain text.
+    entries.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    entries.dedup_by(|a, b| a.name == b.name);
+
+    Ok(entries)
+}
+
+/// An error which can be returned when loading a corpus.
+#[derive(Debug)]
+pub enum CorpusError {
+    IO(io::Error),
+    Toml(PathBuf, TomlError),
+}
+
+impl std::error::Error for CorpusError {}
+
+impl Display for CorpusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorpusError::IO(e) => write!(f, "{e}"),
+            CorpusError::Toml(path, e) => write!(f, "{}: {e}", path.display()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Verdict;
+
+    #[test]
+    fn judges_answers() {
+        assert_eq!(Verdict::new(Some("42"), Some("42")), Verdict::Correct);
+        assert_eq!(
+            Verdict::new(Some("41"), Some("42")),
+            Verdict::Wrong {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(Verdict::new(Some("42"), None), Verdict::Unknown);
+        assert_eq!(Verdict::new(None, Some("42")), Verdict::Failed);
+        assert!(!Verdict::Unknown.is_failure());
+        assert!(Verdict::Failed.is_failure());
+    }
+}
@@ -0,0 +1,272 @@
+/// Property-based cross-checking of two implementations of the same day.
+///
+/// Random inputs are produced by a [`Generator`] and solved by both implementations. The first
+/// input on which they disagree is shrunk to a minimal input that still shows the disagreement:
+/// records are removed with delta debugging, then single records are simplified.
+use std::cell::Cell;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use super::generators::Generator;
+use super::{Answer, Overrides, Solution, solution::solve_with};
+use crate::utils::rng::Rng;
+
+/// Environment variable to override the seed, e.g. to reproduce a reported mismatch.
+pub const SEED_ENV_VAR: &str = "AOC_SEED";
+
+/// The outcome of solving a part, errors and panics are reported as their message.
+pub type Outcome = Result<Answer, String>;
+
+#[derive(Debug, Clone)]
+pub struct Crosscheck {
+    /// Number of random inputs to check.
+    pub cases: usize,
+    /// Passed to [`Generator::records`].
+    pub size: usize,
+    pub seed: u64,
+    /// Parameters of both implementations, e.g. a smaller puzzle constant for small inputs.
+    pub overrides: Overrides,
+}
+
+impl Default for Crosscheck {
+    fn default() -> Self {
+        let seed = std::env::var(SEED_ENV_VAR)
+            .ok()
+            .and_then(|seed| seed.parse().ok())
+            .unwrap_or(2025);
+        Self {
+            cases: 100,
+            size: 20,
+            seed,
+            overrides: Overrides::default(),
+        }
+    }
+}
+
+/// Two implementations disagree on `input`.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    /// Seed of the case that failed before shrinking.
+    pub seed: u64,
+    pub part: u8,
+    pub input: String,
+    pub left: Outcome,
+    pub right: Outcome,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |outcome: &Outcome| match outcome {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {e}"),
+        };
+        writeln!(
+            f,
+            "part {} differs (seed {}, shrunk input below): {} != {}",
+            self.part,
+            self.seed,
+            show(&self.left),
+            show(&self.right)
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+impl Crosscheck {
+    /// Checks many small inputs, for implementations that are slow on larger ones.
+    pub fn small() -> Self {
+        Self {
+            cases: 200,
+            size: 6,
+            ..Self::default()
+        }
+    }
+
+    /// Checks that `A` and `B` agree on the given `parts` of random inputs generated by `G`.
+    pub fn run<G: Generator, A: Solution, B: Solution>(
+        &self,
+        parts: &[u8],
+    ) -> Result<(), Mismatch> {
+        for case in 0..self.cases as u64 {
+            let seed = self.seed.wrapping_add(case);
+            let records = G::records(&mut Rng::new(seed), self.size);
+            for &part in parts {
+                let fails = |records: &[G::Record]| {
+                    disagree::<A, B>(&G::render(records), part, &self.overrides)
+                };
+                if fails(&records).is_none() {
+                    continue;
+                }
+                let records = shrink::<G>(records, fails);
+                let input = G::render(&records);
+                let (left, right) =
+                    disagree::<A, B>(&input, part, &self.overrides).expect("shrunk input agrees");
+                return Err(Mismatch {
+                    seed,
+                    part,
+                    input,
+                    left,
+                    right,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Solves `part` of `input` with both implementations, returns the outcomes if they differ.
+fn disagree<A: Solution, B: Solution>(
+    input: &str,
+    part: u8,
+    overrides: &Overrides,
+) -> Option<(Outcome, Outcome)> {
+    let left = outcome::<A>(input, part, overrides);
+    let right = outcome::<B>(input, part, overrides);
+    match (&left, &right) {
+        (Ok(a), Ok(b)) if a == b => None,
+        (Err(_), Err(_)) => None,
+        _ => Some((left, right)),
+    }
+}
+
+fn outcome<S: Solution>(input: &str, part: u8, overrides: &Overrides) -> Outcome {
+    match catch_panic(|| solve_with::<S>(input, part, overrides)) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(e)) => Err(format!("{e:#}")),
+        Err(message) => Err(message),
+    }
+}
+
+/// Runs `f`, a panic is returned as its message instead of being printed.
+pub(crate) fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    // Panics are expected while shrinking, keep them from flooding the output.
+    silence_panics();
+    SILENT.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENT.set(false);
+
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .map_or_else(
+                || "panicked".into(),
+                |message| format!("panicked: {message}"),
+            )
+    })
+}
+
+thread_local! {
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a panic hook that skips the message of panics on threads marked as `SILENT`. The hook
+/// is process wide, so it is installed once rather than swapped while tests run in parallel.
+fn silence_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENT.get() {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Shrinks the failing `records` to a smaller list for which `fails` still holds.
+fn shrink<G: Generator>(
+    mut records: Vec<G::Record>,
+    fails: impl Fn(&[G::Record]) -> Option<(Outcome, Outcome)>,
+) -> Vec<G::Record> {
+    let still_fails = |records: &[G::Record]| G::is_valid(records) && fails(records).is_some();
+
+    loop {
+        // Delta debugging: try to remove one of `n` chunks, refine the chunks if none can go.
+        let mut n = 2;
+        while records.len() >= 2 {
+            let chunk = records.len().div_ceil(n);
+            let removed = (0..n).find_map(|i| {
+                let start = (i * chunk).min(records.len());
+                let end = ((i + 1) * chunk).min(records.len());
+                let mut rest = records[..start].to_vec();
+                rest.extend_from_slice(&records[end..]);
+                (start < end && still_fails(&rest)).then_some(rest)
+            });
+            match removed {
+                Some(rest) => {
+                    records = rest;
+                    n = (n - 1).max(2);
+                }
+                None if n >= records.len() => break,
+                None => n = (n * 2).min(records.len()),
+            }
+        }
+
+        // Simplify single records, start over if anything changed.
+        let simplified = (0..records.len()).find_map(|i| {
+            G::shrink(&records[i]).into_iter().find_map(|record| {
+                let mut candidate = records.clone();
+                candidate[i] = record;
+                still_fails(&candidate).then_some(candidate)
+            })
+        });
+        match simplified {
+            Some(candidate) => records = candidate,
+            None => return records,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Crosscheck;
+    use crate::template::generators::Day01;
+    use crate::template::{Answer, NoAnswer, Solution};
+
+    /// Sums the distances of the rotations, `BUGGY` miscounts distances from 50.
+    struct Sum<const BUGGY: bool>;
+
+    impl<const BUGGY: bool> Solution for Sum<BUGGY> {
+        type Parsed<'a> = Vec<u64>;
+        type Error = NoAnswer;
+
+        fn parse(input: &str) -> Result<Vec<u64>, NoAnswer> {
+            Ok(input.lines().map(|l| l[1..].parse().unwrap()).collect())
+        }
+
+        fn part_one(distances: &Vec<u64>) -> Result<Answer, NoAnswer> {
+            let sum: u64 = distances
+                .iter()
+                .map(|&d| if BUGGY && d >= 50 { d + 1 } else { d })
+                .sum();
+            Ok(sum.into())
+        }
+
+        fn part_two(_: &Vec<u64>) -> Result<Answer, NoAnswer> {
+            panic!("not implemented")
+        }
+    }
+
+    #[test]
+    fn agrees_on_equal_implementations() {
+        let check = Crosscheck::default();
+        assert!(check.run::<Day01, Sum<false>, Sum<false>>(&[1, 2]).is_ok());
+    }
+
+    #[test]
+    fn shrinks_mismatches() {
+        let mismatch = Crosscheck::default()
+            .run::<Day01, Sum<false>, Sum<true>>(&[1])
+            .unwrap_err();
+        assert_eq!(mismatch.input, "R50\n");
+        assert_eq!(mismatch.left, Ok(50.into()));
+        assert_eq!(mismatch.right, Ok(51.into()));
+    }
+}
@@ -2,11 +2,7 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
-
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+use crate::template::clock::{self, Clock, SystemClock};
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
@@ -37,17 +33,15 @@ impl Day {
     }
 }
 
-#[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if a puzzle of this year's event was released today, `None` otherwise.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        Self::today_with(&SystemClock)
+    }
+
+    /// Like [`Day::today`], reading the time from the provided clock.
+    pub fn today_with(clock: &impl Clock) -> Option<Self> {
+        clock::today(clock, clock::event_year(clock))
     }
 }
 
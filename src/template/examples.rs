@@ -0,0 +1,215 @@
+/// Manifest of the examples of a day and the tests generated from it.
+///
+/// The examples of a day are listed in `data/examples/NN.toml`, one `[[example]]` entry per part:
+///
+/// ```toml
+/// [[example]]
+/// part = 1
+/// expected = 40
+/// params.n_connections = 10   # optional parameters of the solution
+///
+/// [[example]]
+/// file = "08-2.txt"           # defaults to `NN.txt`
+/// part = 2
+/// expected = 25272
+/// name = "larger"             # optional, used to name the test
+/// ```
+///
+/// The build script generates one `#[test]` per entry, which are included into a solution with
+/// [`example_tests!`](crate::example_tests). This module only depends on `std` and [`super::toml`]
+/// so it can be shared with the build script.
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use super::toml::{self, Value};
+
+/// An example input of a day together with its expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub file: String,
+    pub part: u8,
+    pub expected: String,
+    pub params: Vec<(String, String)>,
+    pub name: Option<String>,
+}
+
+/// Parses the examples manifest of a day.
+pub fn parse_manifest(content: &str, day: u8) -> Result<Vec<Example>, String> {
+    let table = toml::parse(content).map_err(|e| e.to_string())?;
+    let Some(entries) = table.get("example") else {
+        return Ok(vec![]);
+    };
+    let entries = entries
+        .as_array()
+        .ok_or("`example` is expected to be an array of tables, i.e. `[[example]]`")?;
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let err = |message: &str| format!("example {}: {message}", i + 1);
+            let entry = entry.as_table().ok_or_else(|| err("expected a table"))?;
+
+            let part = match entry.get("part").and_then(Value::as_integer) {
+                Some(1) => 1,
+                Some(2) => 2,
+                _ => return Err(err("`part` is expected to be 1 or 2")),
+            };
+            let expected = match entry.get("expected") {
+                Some(value @ (Value::String(_) | Value::Integer(_))) => toml::plain(value),
+                _ => return Err(err("`expected` is expected to be an integer or a string")),
+            };
+            let file = match entry.get("file") {
+                None => format!("{day:02}.txt"),
+                Some(Value::String(file)) => file.clone(),
+                Some(_) => return Err(err("`file` is expected to be a string")),
+            };
+            let name = match entry.get("name") {
+                None => None,
+                Some(Value::String(name)) => Some(name.clone()),
+                Some(_) => return Err(err("`name` is expected to be a string")),
+            };
+            let params = match entry.get("params") {
+                None => vec![],
+                Some(Value::Table(params)) => params
+                    .iter()
+                    .map(|(key, value)| (key.clone(), toml::plain(value)))
+                    .collect(),
+                Some(_) => return Err(err("`params` is expected to be a table")),
+            };
+
+            Ok(Example {
+                file,
+                part,
+                expected,
+                params,
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Generates one test function per example. The generated code expects `assert_example` and the
+/// type `ExampleSolution` to be in scope, see [`example_tests!`](crate::example_tests).
+pub fn generate_tests(examples: &[Example]) -> String {
+    let mut names = HashSet::new();
+    let mut code = String::new();
+
+    for example in examples {
+        let part = if example.part == 1 { "one" } else { "two" };
+        let suffix = example
+            .name
+            .as_deref()
+            .unwrap_or_else(|| example.file.trim_end_matches(".txt"));
+        let base = format!("part_{part}_{}", identifier(suffix));
+
+        let mut name = base.clone();
+        let mut i = 2;
+        while !names.insert(name.clone()) {
+            name = format!("{base}_{i}");
+            i += 1;
+        }
+
+        let params: Vec<String> = example
+            .params
+            .iter()
+            .map(|(key, value)| format!("({key:?}, {value:?})"))
+            .collect();
+
+        let _ = write!(
+            code,
+            "#[test]\nfn {name}() {{\n    assert_example::<ExampleSolution>({:?}, {}, {:?}, &[{}]);\n}}\n\n",
+            example.file,
+            example.part,
+            example.expected,
+            params.join(", ")
+        );
+    }
+
+    code
+}
+
+fn identifier(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, generate_tests, parse_manifest};
+
+    #[test]
+    fn parses_manifest() {
+        let examples = parse_manifest(
+            r#"
+            [[example]]
+            part = 1
+            expected = 40
+            params.n_connections = 10
+
+            [[example]]
+            file = "08-2.txt"
+            part = 2
+            expected = "a\nb"
+            name = "Larger one"
+            "#,
+            8,
+        )
+        .unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    file: "08.txt".into(),
+                    part: 1,
+                    expected: "40".into(),
+                    params: vec![("n_connections".into(), "10".into())],
+                    name: None,
+                },
+                Example {
+                    file: "08-2.txt".into(),
+                    part: 2,
+                    expected: "a\nb".into(),
+                    params: vec![],
+                    name: Some("Larger one".into()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        assert!(parse_manifest("[[example]]\npart = 3\nexpected = 1", 1).is_err());
+        assert!(parse_manifest("[[example]]\npart = 1", 1).is_err());
+        assert!(parse_manifest("example = 1", 1).is_err());
+        assert_eq!(parse_manifest("", 1), Ok(vec![]));
+    }
+
+    #[test]
+    fn generates_unique_test_names() {
+        let examples = parse_manifest(
+            "[[example]]\npart = 1\nexpected = 1\n[[example]]\npart = 1\nexpected = 2\n\
+             [[example]]\npart = 2\nexpected = \"x\"\nname = \"Larger one\"\nparams.n = 3",
+            11,
+        )
+        .unwrap();
+        let code = generate_tests(&examples);
+
+        assert!(code.contains("fn part_one_11()"));
+        assert!(code.contains("fn part_one_11_2()"));
+        assert!(code.contains("fn part_two_larger_one()"));
+        assert!(
+            code.contains(r#"assert_example::<ExampleSolution>("11.txt", 2, "x", &[("n", "3")]);"#)
+        );
+    }
+}
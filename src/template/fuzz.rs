@@ -0,0 +1,253 @@
+/// Fuzzing of the input parsers with a deterministic byte mutator, which runs as a normal test on
+/// the stable toolchain.
+///
+/// Every case stacks a few random edits on an input of the corpus: bytes are replaced, inserted or
+/// removed, lines are dropped or repeated, separators, huge numbers and non-ASCII text are
+/// inserted, and inputs are spliced together. A parser may reject any of these inputs with an
+/// error, but must not panic. The first input that makes it panic is shrunk by removing lines,
+/// then characters, and reported.
+use std::fmt::Display;
+use std::fs;
+
+use super::crosscheck::{SEED_ENV_VAR, catch_panic};
+use super::{Day, generators, paths};
+use crate::utils::rng::Rng;
+
+/// Text that tends to break parsers.
+const TOKENS: &[&str] = &[
+    "\n",
+    "\n\n",
+    "\r\n",
+    " ",
+    "\t",
+    ",",
+    "-",
+    ":",
+    "0",
+    "-1",
+    "18446744073709551616",
+    "99999999999999999999999999999999999999999",
+    "ä",
+    "\u{fffd}",
+];
+
+#[derive(Debug, Clone)]
+pub struct Fuzz {
+    /// Number of mutated inputs to parse.
+    pub cases: usize,
+    pub seed: u64,
+    /// Maximum number of edits stacked on one input.
+    pub max_edits: usize,
+}
+
+impl Default for Fuzz {
+    fn default() -> Self {
+        let seed = std::env::var(SEED_ENV_VAR)
+            .ok()
+            .and_then(|seed| seed.parse().ok())
+            .unwrap_or(2025);
+        Self {
+            cases: 2000,
+            seed,
+            max_edits: 4,
+        }
+    }
+}
+
+/// A parser panicked on `input`.
+#[derive(Debug, Clone)]
+pub struct Crash {
+    /// Seed of the case that failed before shrinking.
+    pub seed: u64,
+    pub input: String,
+    pub message: String,
+}
+
+impl Display for Crash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parser {} (seed {}) on the shrunk input {:?}",
+            self.message, self.seed, self.input
+        )
+    }
+}
+
+impl std::error::Error for Crash {}
+
+impl Fuzz {
+    /// The example inputs of `day` and a small generated input.
+    pub fn corpus(&self, day: Day) -> Vec<String> {
+        let mut files: Vec<_> = fs::read_dir(paths::data_dir("examples"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "txt")
+                    && path
+                        .file_name()
+                        .is_some_and(|name| name.to_string_lossy().starts_with(&day.to_string()))
+            })
+            .collect();
+        files.sort();
+
+        let mut corpus: Vec<String> = files
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .collect();
+        corpus.extend(generators::generate(day, Some(6), self.seed));
+        corpus
+    }
+
+    /// Checks that `parse` returns on mutations of the `corpus`, its result is ignored.
+    pub fn run(&self, corpus: &[String], parse: impl Fn(&str)) -> Result<(), Crash> {
+        assert!(!corpus.is_empty(), "empty corpus");
+        let panics = |input: &str| catch_panic(|| parse(input)).err();
+
+        for case in 0..self.cases as u64 {
+            let seed = self.seed.wrapping_add(case);
+            let input = mutate(&mut Rng::new(seed), corpus, self.max_edits);
+            if panics(&input).is_none() {
+                continue;
+            }
+            let input = shrink(input, |input| panics(input).is_some());
+            let message = panics(&input).expect("shrunk input parses");
+            return Err(Crash {
+                seed,
+                input,
+                message,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Stacks up to `max_edits` random edits on a random input of the `corpus`.
+pub fn mutate(rng: &mut Rng, corpus: &[String], max_edits: usize) -> String {
+    let mut bytes = rng.pick(corpus).as_bytes().to_vec();
+    for _ in 0..=rng.index(max_edits.max(1)) {
+        edit(rng, &mut bytes, corpus);
+    }
+    // Broken UTF-8 sequences become replacement characters, parsers only see valid text.
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn edit(rng: &mut Rng, bytes: &mut Vec<u8>, corpus: &[String]) {
+    let len = bytes.len();
+    let pos = rng.index(len + 1);
+    let line_start = bytes[..pos]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    let line_end = bytes[pos..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(len, |i| pos + i + 1);
+
+    match rng.index(9) {
+        // Another byte of the input, likely one that structures it.
+        0 if len > 0 => bytes[pos.min(len - 1)] = *rng.pick(bytes),
+        1 => {
+            let token = rng.pick(TOKENS).as_bytes();
+            bytes.splice(pos..pos, token.iter().copied());
+        }
+        2 => {
+            let end = (pos + 1 + rng.index(8)).min(len);
+            bytes.drain(pos..end);
+        }
+        3 => {
+            let end = (pos + 1 + rng.index(16)).min(len);
+            let chunk = bytes[pos..end].to_vec();
+            bytes.splice(pos..pos, chunk);
+        }
+        4 => bytes.truncate(pos),
+        5 => {
+            bytes.drain(line_start..line_end);
+        }
+        6 => {
+            let line = bytes[line_start..line_end].to_vec();
+            bytes.splice(line_start..line_start, line);
+        }
+        7 => {
+            let other = rng.pick(corpus).as_bytes();
+            bytes.truncate(pos);
+            bytes.extend_from_slice(&other[rng.index(other.len() + 1)..]);
+        }
+        _ => bytes.insert(pos, rng.range(0..256) as u8),
+    }
+}
+
+/// Shrinks the failing `input` by removing lines, then characters, as long as it still fails.
+fn shrink(input: String, fails: impl Fn(&str) -> bool) -> String {
+    let lines = input.split_inclusive('\n').map(String::from).collect();
+    let input = reduce(lines, &fails).concat();
+    let chars = input.chars().map(String::from).collect();
+    reduce(chars, &fails).concat()
+}
+
+/// Delta debugging: removes chunks of `pieces` as long as the rest still fails.
+fn reduce(mut pieces: Vec<String>, fails: impl Fn(&str) -> bool) -> Vec<String> {
+    let mut n = 2;
+    while pieces.len() >= 2 {
+        let chunk = pieces.len().div_ceil(n);
+        let removed = (0..n).find_map(|i| {
+            let start = (i * chunk).min(pieces.len());
+            let end = ((i + 1) * chunk).min(pieces.len());
+            let rest = [&pieces[..start], &pieces[end..]].concat();
+            (start < end && fails(&rest.concat())).then_some(rest)
+        });
+        match removed {
+            Some(rest) => {
+                pieces = rest;
+                n = (n - 1).max(2);
+            }
+            None if n >= pieces.len() => break,
+            None => n = (n * 2).min(pieces.len()),
+        }
+    }
+    pieces
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Fuzz, mutate};
+    use crate::utils::input::{ParseError, parse_at};
+    use crate::utils::rng::Rng;
+
+    fn corpus() -> Vec<String> {
+        vec!["12\n345\n6\n".to_string(), "7\n8\n".to_string()]
+    }
+
+    #[test]
+    fn accepts_parsers_that_return_errors() {
+        let parse = |input: &str| {
+            let _: Result<Vec<u8>, ParseError> =
+                input.lines().map(|l| parse_at(input, l)).collect();
+        };
+        assert!(Fuzz::default().run(&corpus(), parse).is_ok());
+    }
+
+    #[test]
+    fn shrinks_crashes() {
+        let parse = |input: &str| {
+            let _: Vec<u64> = input.lines().map(|l| l.parse().unwrap()).collect();
+        };
+        let crash = Fuzz::default().run(&corpus(), parse).unwrap_err();
+        assert!(
+            crash.input.chars().count() <= 2,
+            "not shrunk: {:?}",
+            crash.input
+        );
+        assert!(crash.message.starts_with("panicked: "));
+    }
+
+    #[test]
+    fn mutates_deterministically() {
+        let inputs = |seed| mutate(&mut Rng::new(seed), &corpus(), 4);
+        assert_eq!(inputs(7), inputs(7));
+        assert!((0..20).any(|seed| inputs(seed) != inputs(0)));
+    }
+}
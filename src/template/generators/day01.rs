@@ -0,0 +1,56 @@
+/// Day 1: one rotation of the dial per line, e.g. `L68` or `R14`.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day01;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rotation {
+    pub left: bool,
+    pub distance: u32,
+}
+
+impl Generator for Day01 {
+    type Record = Rotation;
+
+    const SIZE: usize = 4000;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Rotation> {
+        (0..size.max(1))
+            .map(|_| Rotation {
+                left: rng.chance(0.5),
+                // Mostly less than a full turn, sometimes several.
+                distance: if rng.chance(0.8) {
+                    rng.range(1..100) as u32
+                } else {
+                    rng.range(100..1000) as u32
+                },
+            })
+            .collect()
+    }
+
+    fn render(records: &[Rotation]) -> String {
+        render_lines(records, |r| {
+            format!("{}{}", if r.left { 'L' } else { 'R' }, r.distance)
+        })
+    }
+
+    fn shrink(record: &Rotation) -> Vec<Rotation> {
+        let mut candidates = vec![];
+        for distance in [record.distance / 2, record.distance - 1] {
+            if distance > 0 && distance != record.distance {
+                candidates.push(Rotation {
+                    distance,
+                    ..*record
+                });
+            }
+        }
+        if record.left {
+            candidates.push(Rotation {
+                left: false,
+                ..*record
+            });
+        }
+        candidates
+    }
+}
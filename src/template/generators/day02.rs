@@ -0,0 +1,54 @@
+/// Day 2: comma separated, disjoint ranges of product ids on a single line, e.g. `11-22,95-115`.
+use super::Generator;
+use crate::utils::rng::Rng;
+
+pub struct Day02;
+
+/// An inclusive range of ids, ids have no leading zeroes so they start at 1.
+pub type IdRange = (u64, u64);
+
+impl Generator for Day02 {
+    type Record = IdRange;
+
+    const SIZE: usize = 35;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<IdRange> {
+        let mut ranges = Vec::with_capacity(size.max(1));
+        let mut start = rng.range(1..100);
+        for _ in 0..size.max(1) {
+            // Ranges span at most one additional digit, like the puzzle input.
+            let len = rng.range(0..start.max(10));
+            ranges.push((start, start + len));
+            start += len + 1 + rng.range(0..start * 2);
+            if start > 1_000_000_000 {
+                break;
+            }
+        }
+        rng.shuffle(&mut ranges);
+        ranges
+    }
+
+    fn render(records: &[IdRange]) -> String {
+        let ranges: Vec<String> = records.iter().map(|(a, b)| format!("{a}-{b}")).collect();
+        ranges.join(",") + "\n"
+    }
+
+    fn shrink(&(start, end): &IdRange) -> Vec<IdRange> {
+        [
+            (start, start + (end - start) / 2),
+            (start, end - 1),
+            ((start / 10).max(1), (end / 10).max(1)),
+        ]
+        .into_iter()
+        .filter(|&r| r != (start, end) && r.0 <= r.1)
+        .collect()
+    }
+
+    fn is_valid(records: &[IdRange]) -> bool {
+        let mut sorted = records.to_vec();
+        sorted.sort_unstable();
+        !sorted.is_empty()
+            && sorted.iter().all(|&(a, b)| 1 <= a && a <= b)
+            && sorted.windows(2).all(|w| w[0].1 < w[1].0)
+    }
+}
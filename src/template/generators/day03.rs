@@ -0,0 +1,55 @@
+/// Day 3: one bank of batteries per line, i.e. a string of the digits 1 to 9.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day03;
+
+/// Part two turns on twelve batteries of every bank.
+const MIN_LEN: usize = 12;
+
+impl Generator for Day03 {
+    type Record = Vec<u8>;
+
+    const SIZE: usize = 200;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Vec<u8>> {
+        (0..size.max(1))
+            .map(|_| {
+                let len = rng.range(MIN_LEN as u64..101) as usize;
+                (0..len).map(|_| rng.range(1..10) as u8).collect()
+            })
+            .collect()
+    }
+
+    fn render(records: &[Vec<u8>]) -> String {
+        render_lines(records, |bank| {
+            bank.iter().map(|d| char::from(b'0' + d)).collect()
+        })
+    }
+
+    fn shrink(bank: &Vec<u8>) -> Vec<Vec<u8>> {
+        let mut candidates = vec![];
+        if bank.len() > MIN_LEN {
+            for i in 0..bank.len() {
+                let mut shorter = bank.clone();
+                shorter.remove(i);
+                candidates.push(shorter);
+            }
+        }
+        for i in 0..bank.len() {
+            if bank[i] > 1 {
+                let mut lower = bank.clone();
+                lower[i] = 1;
+                candidates.push(lower);
+            }
+        }
+        candidates
+    }
+
+    fn is_valid(records: &[Vec<u8>]) -> bool {
+        !records.is_empty()
+            && records
+                .iter()
+                .all(|bank| bank.len() >= MIN_LEN && bank.iter().all(|d| (1..=9).contains(d)))
+    }
+}
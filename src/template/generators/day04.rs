@@ -0,0 +1,44 @@
+/// Day 4: a grid of paper rolls `@` and empty floor `.`.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day04;
+
+impl Generator for Day04 {
+    /// A row of the grid, `true` for a roll of paper.
+    type Record = Vec<bool>;
+
+    const SIZE: usize = 140;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Vec<bool>> {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| (0..size).map(|_| rng.chance(0.6)).collect())
+            .collect()
+    }
+
+    fn render(records: &[Vec<bool>]) -> String {
+        render_lines(records, |row| {
+            row.iter()
+                .map(|&roll| if roll { '@' } else { '.' })
+                .collect()
+        })
+    }
+
+    fn shrink(row: &Vec<bool>) -> Vec<Vec<bool>> {
+        (0..row.len())
+            .filter(|&i| row[i])
+            .map(|i| {
+                let mut row = row.clone();
+                row[i] = false;
+                row
+            })
+            .collect()
+    }
+
+    fn is_valid(records: &[Vec<bool>]) -> bool {
+        !records.is_empty()
+            && !records[0].is_empty()
+            && records.iter().all(|row| row.len() == records[0].len())
+    }
+}
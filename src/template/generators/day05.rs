@@ -0,0 +1,61 @@
+/// Day 5: fresh ingredient id ranges, a blank line, then the available ingredient ids.
+use super::Generator;
+use crate::utils::rng::Rng;
+
+pub struct Day05;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry {
+    /// An inclusive range of fresh ids, ranges may overlap.
+    Range(u64, u64),
+    Id(u64),
+}
+
+impl Generator for Day05 {
+    type Record = Entry;
+
+    const SIZE: usize = 200;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Entry> {
+        let size = size.max(1) as u64;
+        let max = size * 100;
+        let mut entries: Vec<Entry> = (0..size)
+            .map(|_| {
+                let start = rng.range(1..max);
+                Entry::Range(start, start + rng.range(0..max / 4))
+            })
+            .collect();
+        entries.extend((0..size * 2).map(|_| Entry::Id(rng.range(1..max + max / 4))));
+        entries
+    }
+
+    fn render(records: &[Entry]) -> String {
+        let mut ranges = String::new();
+        let mut ids = String::new();
+        for entry in records {
+            match entry {
+                Entry::Range(a, b) => ranges += &format!("{a}-{b}\n"),
+                Entry::Id(id) => ids += &format!("{id}\n"),
+            }
+        }
+        ranges + "\n" + &ids
+    }
+
+    fn shrink(entry: &Entry) -> Vec<Entry> {
+        match *entry {
+            Entry::Range(a, b) if a < b => {
+                vec![Entry::Range(a, a + (b - a) / 2), Entry::Range(a, b - 1)]
+            }
+            Entry::Range(..) => vec![],
+            Entry::Id(id) => [id / 2, id - 1]
+                .into_iter()
+                .filter(|&smaller| smaller > 0)
+                .map(Entry::Id)
+                .collect(),
+        }
+    }
+
+    fn is_valid(records: &[Entry]) -> bool {
+        records.iter().any(|e| matches!(e, Entry::Range(..)))
+    }
+}
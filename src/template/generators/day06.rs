@@ -0,0 +1,93 @@
+/// Day 6: a worksheet of problems written in columns, the operator of each problem is on the last
+/// line, below its first column.
+use super::Generator;
+use crate::utils::rng::Rng;
+
+pub struct Day06;
+
+/// Numbers of a problem are listed top to bottom, all problems have the same count of numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub numbers: Vec<u64>,
+    pub multiply: bool,
+    /// Whether the numbers are aligned to the left of the column, otherwise to the right.
+    pub left_aligned: bool,
+}
+
+impl Generator for Day06 {
+    type Record = Problem;
+
+    const SIZE: usize = 1000;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Problem> {
+        let rows = rng.range(3..5) as usize;
+        (0..size.max(1))
+            .map(|_| Problem {
+                // Digits are never zero, a column of part two would be read as a separator.
+                numbers: (0..rows)
+                    .map(|_| (0..rng.range(1..5)).fold(0, |n, _| n * 10 + rng.range(1..10)))
+                    .collect(),
+                multiply: rng.chance(0.5),
+                left_aligned: rng.chance(0.5),
+            })
+            .collect()
+    }
+
+    fn render(records: &[Problem]) -> String {
+        let rows = records.first().map_or(0, |p| p.numbers.len());
+        let mut lines = vec![String::new(); rows + 1];
+        for (i, problem) in records.iter().enumerate() {
+            let width = problem.numbers.iter().map(|n| n.to_string().len()).max();
+            let width = width.unwrap_or(1);
+            for (line, n) in lines.iter_mut().zip(&problem.numbers) {
+                if i > 0 {
+                    line.push(' ');
+                }
+                if problem.left_aligned {
+                    line.push_str(&format!("{n:<width$}"));
+                } else {
+                    line.push_str(&format!("{n:>width$}"));
+                }
+            }
+            if i > 0 {
+                lines[rows].push(' ');
+            }
+            let op = if problem.multiply { '*' } else { '+' };
+            lines[rows].push_str(&format!("{op:<width$}"));
+        }
+        lines.iter().map(|l| format!("{l}\n")).collect()
+    }
+
+    fn shrink(problem: &Problem) -> Vec<Problem> {
+        let mut candidates = vec![];
+        if problem.multiply {
+            candidates.push(Problem {
+                multiply: false,
+                ..problem.clone()
+            });
+        }
+        for i in 0..problem.numbers.len() {
+            let n = problem.numbers[i];
+            for smaller in [n / 10, 1] {
+                if smaller > 0 && smaller < n {
+                    let mut numbers = problem.numbers.clone();
+                    numbers[i] = smaller;
+                    candidates.push(Problem {
+                        numbers,
+                        ..problem.clone()
+                    });
+                }
+            }
+        }
+        candidates
+    }
+
+    fn is_valid(records: &[Problem]) -> bool {
+        !records.is_empty()
+            && !records[0].numbers.is_empty()
+            && records.iter().all(|p| {
+                p.numbers.len() == records[0].numbers.len()
+                    && p.numbers.iter().all(|&n| !n.to_string().contains('0'))
+            })
+    }
+}
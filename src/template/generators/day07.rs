@@ -0,0 +1,71 @@
+/// Day 7: a manifold with the beam start `S` on the first line and rows of splitters `^` on every
+/// other line below it.
+///
+/// Like the puzzle input, the splitters stand in a cone below the start, never next to each other
+/// or at the border. The number of timelines grows quickly with the number of rows.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day07;
+
+impl Generator for Day07 {
+    /// A row of splitters, `true` for a splitter.
+    type Record = Vec<bool>;
+
+    const SIZE: usize = 70;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Vec<bool>> {
+        let size = size.max(1);
+        let width = 2 * size + 3;
+        let start = width / 2;
+        (0..size)
+            .map(|row| {
+                let mut splitters = vec![false; width];
+                for offset in (0..=row).map(|k| 2 * k) {
+                    if rng.chance(0.6) {
+                        splitters[start - row + offset] = true;
+                    }
+                }
+                splitters
+            })
+            .collect()
+    }
+
+    fn render(records: &[Vec<bool>]) -> String {
+        let width = records.first().map_or(1, Vec::len);
+        let mut lines = vec![];
+        let mut start = vec!['.'; width];
+        start[width / 2] = 'S';
+        lines.push(start.into_iter().collect());
+        for row in records {
+            lines.push(".".repeat(width));
+            lines.push(row.iter().map(|&s| if s { '^' } else { '.' }).collect());
+        }
+        lines.push(".".repeat(width));
+        render_lines(&lines, String::clone)
+    }
+
+    fn shrink(row: &Vec<bool>) -> Vec<Vec<bool>> {
+        (0..row.len())
+            .filter(|&i| row[i])
+            .map(|i| {
+                let mut row = row.clone();
+                row[i] = false;
+                row
+            })
+            .collect()
+    }
+
+    fn is_valid(records: &[Vec<bool>]) -> bool {
+        let Some(width) = records.first().map(Vec::len) else {
+            return false;
+        };
+        width >= 3
+            && records.iter().all(|row| {
+                row.len() == width
+                    && !row[0]
+                    && !row[width - 1]
+                    && row.windows(2).all(|w| !(w[0] && w[1]))
+            })
+    }
+}
@@ -0,0 +1,40 @@
+/// Day 8: the positions of the junction boxes, one `x,y,z` per line.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day08;
+
+/// Coordinates are below this bound, like in the puzzle input.
+const BOUND: u64 = 100_000;
+
+impl Generator for Day08 {
+    type Record = [u64; 3];
+
+    /// Part one connects 1000 pairs, smaller inputs need to override `n_connections`.
+    const SIZE: usize = 1000;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<[u64; 3]> {
+        (0..size.max(2))
+            .map(|_| [(); 3].map(|_| rng.range(0..BOUND)))
+            .collect()
+    }
+
+    fn render(records: &[[u64; 3]]) -> String {
+        render_lines(records, |[x, y, z]| format!("{x},{y},{z}"))
+    }
+
+    fn shrink(position: &[u64; 3]) -> Vec<[u64; 3]> {
+        (0..3)
+            .filter(|&i| position[i] > 0)
+            .map(|i| {
+                let mut closer = *position;
+                closer[i] /= 2;
+                closer
+            })
+            .collect()
+    }
+
+    fn is_valid(records: &[[u64; 3]]) -> bool {
+        records.len() >= 2
+    }
+}
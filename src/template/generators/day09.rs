@@ -0,0 +1,71 @@
+/// Day 9: the red tiles, i.e. the corners of a rectilinear polygon in order, one `x,y` per line.
+///
+/// The polygon is generated as a skyline of columns standing on the line `y = 0`, which keeps it
+/// valid when columns are removed or resized.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day09;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub width: u64,
+    pub height: u64,
+}
+
+impl Generator for Day09 {
+    type Record = Column;
+
+    const SIZE: usize = 250;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Column> {
+        let mut columns: Vec<Column> = Vec::with_capacity(size.max(1));
+        while columns.len() < size.max(1) {
+            let height = rng.range(1..100);
+            if columns.last().is_some_and(|c| c.height == height) {
+                continue;
+            }
+            columns.push(Column {
+                width: rng.range(1..20),
+                height,
+            });
+        }
+        columns
+    }
+
+    fn render(records: &[Column]) -> String {
+        let mut corners = vec![];
+        let mut x = 1;
+        for (i, column) in records.iter().enumerate() {
+            if i == 0 {
+                corners.push((x, 0));
+            }
+            corners.push((x, column.height));
+            x += column.width;
+            corners.push((x, column.height));
+        }
+        corners.push((x, 0));
+        render_lines(&corners, |(x, y)| format!("{x},{y}"))
+    }
+
+    fn shrink(column: &Column) -> Vec<Column> {
+        let mut candidates = vec![];
+        for width in [1, column.width / 2] {
+            if width > 0 && width < column.width {
+                candidates.push(Column { width, ..*column });
+            }
+        }
+        for height in [1, column.height / 2, column.height - 1] {
+            if height > 0 && height < column.height {
+                candidates.push(Column { height, ..*column });
+            }
+        }
+        candidates
+    }
+
+    fn is_valid(records: &[Column]) -> bool {
+        !records.is_empty()
+            && records.iter().all(|c| c.width > 0 && c.height > 0)
+            && records.windows(2).all(|w| w[0].height != w[1].height)
+    }
+}
@@ -0,0 +1,82 @@
+/// Day 10: one machine per line, e.g. `[.##.] (3) (1,3) (2) {3,5,4,7}` with the indicator lights,
+/// the buttons and the joltage requirements.
+///
+/// The targets are the result of pressing random buttons, so every machine can be solved.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub lights: Vec<bool>,
+    /// The lights or counters toggled by each button.
+    pub buttons: Vec<Vec<usize>>,
+    pub joltage: Vec<u64>,
+}
+
+impl Machine {
+    /// A machine whose targets are reached by pressing each button `presses[i]` times for the
+    /// joltage and by pressing the buttons in `toggled` once for the lights.
+    fn new(n: usize, buttons: Vec<Vec<usize>>, presses: &[u64], toggled: &[bool]) -> Self {
+        let mut lights = vec![false; n];
+        let mut joltage = vec![0; n];
+        for (i, button) in buttons.iter().enumerate() {
+            for &j in button {
+                lights[j] ^= toggled[i];
+                joltage[j] += presses[i];
+            }
+        }
+        Self {
+            lights,
+            buttons,
+            joltage,
+        }
+    }
+}
+
+impl Generator for Day10 {
+    type Record = Machine;
+
+    const SIZE: usize = 150;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Machine> {
+        (0..size.max(1))
+            .map(|_| {
+                let n = rng.range(3..11) as usize;
+                let buttons: Vec<Vec<usize>> = (0..rng.range(3..14))
+                    .map(|_| {
+                        let mut button: Vec<usize> = (0..n).filter(|_| rng.chance(0.4)).collect();
+                        if button.is_empty() {
+                            button.push(rng.index(n));
+                        }
+                        button
+                    })
+                    .collect();
+                let presses: Vec<u64> = buttons.iter().map(|_| rng.range(0..30)).collect();
+                let toggled: Vec<bool> = buttons.iter().map(|_| rng.chance(0.5)).collect();
+                Machine::new(n, buttons, &presses, &toggled)
+            })
+            .collect()
+    }
+
+    fn render(records: &[Machine]) -> String {
+        render_lines(records, |machine| {
+            let lights: String = machine
+                .lights
+                .iter()
+                .map(|&on| if on { '#' } else { '.' })
+                .collect();
+            let buttons: Vec<String> = machine
+                .buttons
+                .iter()
+                .map(|b| {
+                    let b: Vec<String> = b.iter().map(ToString::to_string).collect();
+                    format!("({})", b.join(","))
+                })
+                .collect();
+            let joltage: Vec<String> = machine.joltage.iter().map(ToString::to_string).collect();
+            format!("[{lights}] {} {{{}}}", buttons.join(" "), joltage.join(","))
+        })
+    }
+}
@@ -0,0 +1,99 @@
+/// Day 11: the devices and their outputs, one `name: output output ...` per line.
+///
+/// The devices form layers, every device is connected to the next layer and reached from the
+/// previous one. `svr` is the first and `out` the last layer, `you`, `fft` and `dac` are placed in
+/// between in this order. The number of layers is capped to keep the path counts within `u64`.
+use super::{Generator, render_lines};
+use crate::utils::rng::Rng;
+
+pub struct Day11;
+
+const MAX_LAYERS: usize = 30;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Device {
+    pub name: String,
+    pub outputs: Vec<String>,
+}
+
+impl Generator for Day11 {
+    type Record = Device;
+
+    const SIZE: usize = 600;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Device> {
+        let size = size.max(8);
+        let n_layers = (size / 4).clamp(5, MAX_LAYERS);
+        let width = (size - 2) / (n_layers - 2);
+
+        let mut names = vec!["you", "svr", "fft", "dac", "out"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let mut name = |rng: &mut Rng| loop {
+            let candidate: String = (0..3)
+                .map(|_| (b'a' + rng.range(0..26) as u8) as char)
+                .collect();
+            if !names.contains(&candidate) {
+                names.push(candidate.clone());
+                return candidate;
+            }
+        };
+
+        let mut layers: Vec<Vec<String>> = vec![vec!["svr".into()]];
+        for i in 1..n_layers - 1 {
+            let mut layer: Vec<String> = (0..width).map(|_| name(rng)).collect();
+            let special = [
+                (n_layers / 4, "you"),
+                (n_layers / 2, "fft"),
+                (3 * n_layers / 4, "dac"),
+            ];
+            for (at, special) in special {
+                if i == at.clamp(1, n_layers - 2) {
+                    let j = rng.index(layer.len());
+                    layer[j] = special.into();
+                }
+            }
+            layers.push(layer);
+        }
+        layers.push(vec!["out".into()]);
+
+        let mut devices = vec![];
+        for pair in layers.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+            let mut outputs: Vec<Vec<String>> = from
+                .iter()
+                .map(|_| {
+                    let mut outputs = vec![];
+                    for _ in 0..rng.range(1..4) {
+                        let output = rng.pick(to).clone();
+                        if !outputs.contains(&output) {
+                            outputs.push(output);
+                        }
+                    }
+                    outputs
+                })
+                .collect();
+            // Every device is reached from the previous layer.
+            for device in to {
+                if !outputs.iter().any(|o| o.contains(device)) {
+                    outputs[rng.index(from.len())].push(device.clone());
+                }
+            }
+            for (name, outputs) in from.iter().zip(outputs) {
+                devices.push(Device {
+                    name: name.clone(),
+                    outputs,
+                });
+            }
+        }
+        rng.shuffle(&mut devices);
+        devices
+    }
+
+    fn render(records: &[Device]) -> String {
+        render_lines(records, |device| {
+            format!("{}: {}", device.name, device.outputs.join(" "))
+        })
+    }
+}
@@ -0,0 +1,127 @@
+/// Day 12: the shapes of the presents followed by the regions under the trees, e.g. `12x5: 1 0 1`
+/// with the size of the region and the count of presents of each shape.
+use super::Generator;
+use crate::utils::rng::Rng;
+
+pub struct Day12;
+
+const N_SHAPES: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry {
+    /// A present occupying the `true` cells of a 3x3 square.
+    Shape([[bool; 3]; 3]),
+    Region {
+        width: u64,
+        height: u64,
+        counts: Vec<u64>,
+    },
+}
+
+impl Generator for Day12 {
+    type Record = Entry;
+
+    const SIZE: usize = 1000;
+
+    fn records(rng: &mut Rng, size: usize) -> Vec<Entry> {
+        let shapes: Vec<[[bool; 3]; 3]> = (0..N_SHAPES)
+            .map(|_| {
+                // The center is always part of the shape, like in the puzzle input.
+                let mut shape = [[false; 3]; 3].map(|row| row.map(|_| rng.chance(0.7)));
+                shape[1][1] = true;
+                shape
+            })
+            .collect();
+        let cells: Vec<u64> = shapes
+            .iter()
+            .map(|s| s.iter().flatten().filter(|&&c| c).count() as u64)
+            .collect();
+
+        let mut entries: Vec<Entry> = shapes.into_iter().map(Entry::Shape).collect();
+        for _ in 0..size.max(1) {
+            let (width, height) = (rng.range(4..51), rng.range(4..51));
+            let mut counts = vec![0; N_SHAPES];
+            let mut occupied = 0;
+            // Either few enough presents to fit side by side, or more cells than the region has.
+            if rng.chance(0.5) {
+                for _ in 0..(width / 3) * (height / 3) {
+                    counts[rng.index(N_SHAPES)] += 1;
+                }
+            } else {
+                while occupied <= width * height {
+                    let i = rng.index(N_SHAPES);
+                    counts[i] += 1;
+                    occupied += cells[i];
+                }
+            }
+            entries.push(Entry::Region {
+                width,
+                height,
+                counts,
+            });
+        }
+        entries
+    }
+
+    fn render(records: &[Entry]) -> String {
+        let mut shapes = vec![];
+        let mut regions = String::new();
+        for entry in records {
+            match entry {
+                Entry::Shape(shape) => {
+                    let rows: Vec<String> = shape
+                        .iter()
+                        .map(|row| row.iter().map(|&c| if c { '#' } else { '.' }).collect())
+                        .collect();
+                    shapes.push(format!("{}:\n{}\n", shapes.len(), rows.join("\n")));
+                }
+                Entry::Region {
+                    width,
+                    height,
+                    counts,
+                } => {
+                    let counts: Vec<String> = counts.iter().map(ToString::to_string).collect();
+                    regions += &format!("{width}x{height}: {}\n", counts.join(" "));
+                }
+            }
+        }
+        shapes.join("\n") + "\n" + &regions
+    }
+
+    fn shrink(entry: &Entry) -> Vec<Entry> {
+        let Entry::Region {
+            width,
+            height,
+            counts,
+        } = entry
+        else {
+            return vec![];
+        };
+        (0..counts.len())
+            .filter(|&i| counts[i] > 0)
+            .map(|i| {
+                let mut counts = counts.clone();
+                counts[i] -= 1;
+                Entry::Region {
+                    width: *width,
+                    height: *height,
+                    counts,
+                }
+            })
+            .collect()
+    }
+
+    fn is_valid(records: &[Entry]) -> bool {
+        let shapes = records
+            .iter()
+            .filter(|e| matches!(e, Entry::Shape(_)))
+            .count();
+        records.iter().any(|e| match e {
+            Entry::Region { counts, .. } => counts.len() == shapes,
+            Entry::Shape(_) => false,
+        }) && records.iter().all(|e| match e {
+            Entry::Region { counts, .. } => counts.len() == shapes,
+            Entry::Shape(_) => true,
+        })
+    }
+}
@@ -0,0 +1,136 @@
+/// Generators of random, valid puzzle inputs, used to cross-check implementations of a day.
+///
+/// An input is generated as a list of records, e.g. the rotations of day 1, that are rendered to
+/// the text of the input. Failing inputs are shrunk by removing records and by simplifying single
+/// records, see [`crosscheck`](super::crosscheck).
+use std::fmt::Debug;
+
+use super::Day;
+use crate::utils::rng::Rng;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+
+pub use day01::Day01;
+pub use day02::Day02;
+pub use day03::Day03;
+pub use day04::Day04;
+pub use day05::Day05;
+pub use day06::Day06;
+pub use day07::Day07;
+pub use day08::Day08;
+pub use day09::Day09;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+
+pub trait Generator {
+    type Record: Clone + Debug;
+
+    /// The size of an input that resembles the puzzle input.
+    const SIZE: usize;
+
+    /// Generates the records of a random input, `size` scales their number.
+    fn records(rng: &mut Rng, size: usize) -> Vec<Self::Record>;
+
+    /// Renders records to the text of an input.
+    fn render(records: &[Self::Record]) -> String;
+
+    /// Simpler versions of a record, tried when shrinking a failing input.
+    fn shrink(_record: &Self::Record) -> Vec<Self::Record> {
+        vec![]
+    }
+
+    /// Whether the records form a valid input. Shrinking only considers valid inputs.
+    fn is_valid(records: &[Self::Record]) -> bool {
+        !records.is_empty()
+    }
+
+    /// Generates the text of a random input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        Self::render(&Self::records(rng, size))
+    }
+}
+
+/// Generates a random input for `day` from `seed`, `None` if there is no generator for the day.
+/// Without a `size`, the input resembles the puzzle input in size.
+pub fn generate(day: Day, size: Option<usize>, seed: u64) -> Option<String> {
+    fn run<G: Generator>(size: Option<usize>, seed: u64) -> String {
+        G::generate(&mut Rng::new(seed), size.unwrap_or(G::SIZE))
+    }
+
+    let input = match day.into_inner() {
+        1 => run::<Day01>(size, seed),
+        2 => run::<Day02>(size, seed),
+        3 => run::<Day03>(size, seed),
+        4 => run::<Day04>(size, seed),
+        5 => run::<Day05>(size, seed),
+        6 => run::<Day06>(size, seed),
+        7 => run::<Day07>(size, seed),
+        8 => run::<Day08>(size, seed),
+        9 => run::<Day09>(size, seed),
+        10 => run::<Day10>(size, seed),
+        11 => run::<Day11>(size, seed),
+        12 => run::<Day12>(size, seed),
+        _ => return None,
+    };
+    Some(input)
+}
+
+/// Renders records one per line, with a trailing newline.
+fn render_lines<T>(records: &[T], line: impl Fn(&T) -> String) -> String {
+    records.iter().map(|r| line(r) + "\n").collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        Day01, Day02, Day03, Day04, Day05, Day06, Day07, Day08, Day09, Day10, Day11, Day12,
+        Generator,
+    };
+    use crate::utils::rng::Rng;
+
+    fn check<G: Generator>(size: usize) {
+        let mut rng = Rng::new(42);
+        for _ in 0..50 {
+            let records = G::records(&mut rng, size);
+            assert!(G::is_valid(&records), "invalid records {records:?}");
+            assert!(!G::render(&records).is_empty());
+        }
+    }
+
+    #[test]
+    fn generates_valid_inputs() {
+        check::<Day01>(20);
+        check::<Day02>(10);
+        check::<Day03>(10);
+        check::<Day04>(10);
+        check::<Day05>(20);
+        check::<Day06>(10);
+        check::<Day07>(10);
+        check::<Day08>(10);
+        check::<Day09>(10);
+        check::<Day10>(10);
+        check::<Day11>(40);
+        check::<Day12>(10);
+    }
+
+    #[test]
+    fn is_deterministic() {
+        let a = Day05::generate(&mut Rng::new(3), 10);
+        let b = Day05::generate(&mut Rng::new(3), 10);
+        assert_eq!(a, b);
+    }
+}
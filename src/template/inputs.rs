@@ -0,0 +1,243 @@
+/// Storage of puzzle inputs, which may be encrypted at rest so they can be committed to a public
+/// repository without publishing them.
+///
+/// An encrypted file is stored next to its plain text location with an additional `.enc`
+/// extension, e.g. `data/inputs/01.txt.enc`. The key is read from the `AOC_INPUT_KEY` environment
+/// variable or from the configured `input_key_file`. It should be a random secret like the output
+/// of `openssl rand -hex 32`: anyone holding an encrypted file can try to guess it.
+///
+/// Files are encrypted with XChaCha20-Poly1305 under a key derived from the secret with Argon2id.
+/// Each file stores its own random salt and nonce, so encrypting the same input twice gives
+/// different files; [`store`] keeps an existing file if it already holds the same input.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+
+use crate::template::config::{Config, InputStorage};
+
+pub const KEY_ENV_VAR: &str = "AOC_INPUT_KEY";
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+const MAGIC: &[u8; 8] = b"AOC-ENC2";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// The secret that input keys are derived from.
+pub struct Key {
+    secret: String,
+}
+
+impl Key {
+    /// A key from a secret string, surrounding whitespace like a trailing newline is ignored.
+    pub fn from_secret(secret: &str) -> Self {
+        Self {
+            secret: secret.trim().to_string(),
+        }
+    }
+
+    /// Loads the key from `AOC_INPUT_KEY`, falling back to the configured key file.
+    pub fn load() -> Result<Self, InputError> {
+        if let Ok(secret) = env::var(KEY_ENV_VAR)
+            && !secret.trim().is_empty()
+        {
+            return Ok(Self::from_secret(&secret));
+        }
+
+        let path = Config::get()
+            .input_key_file
+            .as_ref()
+            .ok_or(InputError::MissingKey)?;
+        let secret = fs::read_to_string(path).map_err(|e| InputError::KeyFile(path.clone(), e))?;
+        Ok(Self::from_secret(&secret))
+    }
+
+    /// The cipher for a file, keyed with Argon2id of the secret and the salt of the file.
+    fn cipher(&self, salt: &[u8]) -> XChaCha20Poly1305 {
+        let mut key = [0; 32];
+        Argon2::default()
+            .hash_password_into(self.secret.as_bytes(), salt, &mut key)
+            .expect("the salt and key lengths are valid");
+        XChaCha20Poly1305::new(&key.into())
+    }
+}
+
+/// Encrypts `plaintext` into the format of an encrypted input file: the magic bytes, salt and
+/// nonce followed by the ciphertext and its authentication tag.
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher(&salt)
+        .encrypt(&nonce, plaintext)
+        .expect("inputs are small enough to encrypt");
+
+    let mut out = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    out
+}
+
+/// Decrypts the contents of an encrypted input file, verifying that it was not tampered with and
+/// that the correct key is used.
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, InputError> {
+    let data = data.strip_prefix(MAGIC).ok_or(InputError::InvalidFormat)?;
+    if data.len() < SALT_LEN + NONCE_LEN {
+        return Err(InputError::InvalidFormat);
+    }
+    let (salt, data) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    key.cipher(salt)
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| InputError::WrongKey)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Location of the encrypted variant of a data file.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(ENCRYPTED_EXTENSION);
+    PathBuf::from(path)
+}
+
+/// Reads a data file, transparently decrypting it if an encrypted variant exists.
+pub fn read(path: &Path) -> Result<String, InputError> {
+    let encrypted = encrypted_path(path);
+    if !encrypted.is_file() {
+        return fs::read_to_string(path).map_err(InputError::IO);
+    }
+
+    let data = fs::read(&encrypted).map_err(InputError::IO)?;
+    let plaintext = decrypt(&Key::load()?, &data)?;
+    String::from_utf8(plaintext).map_err(|_| InputError::InvalidFormat)
+}
+
+/// Moves a freshly written plain text input to the configured storage, returning its location.
+/// A stale copy in the other storage mode is removed.
+pub fn store(path: &Path) -> Result<PathBuf, InputError> {
+    let encrypted = encrypted_path(path);
+
+    match Config::get().input_storage {
+        InputStorage::Plain => {
+            if encrypted.is_file() {
+                fs::remove_file(&encrypted).map_err(InputError::IO)?;
+            }
+            Ok(path.to_path_buf())
+        }
+        InputStorage::Encrypted => {
+            let key = Key::load()?;
+            let plaintext = fs::read(path).map_err(InputError::IO)?;
+            // Encryption is randomised, keep an unchanged input to not produce a diff.
+            let unchanged = fs::read(&encrypted)
+                .ok()
+                .and_then(|data| decrypt(&key, &data).ok())
+                .is_some_and(|stored| stored == plaintext);
+            if !unchanged {
+                fs::write(&encrypted, encrypt(&key, &plaintext)).map_err(InputError::IO)?;
+            }
+            fs::remove_file(path).map_err(InputError::IO)?;
+            Ok(encrypted)
+        }
+    }
+}
+
+/// An error which can be returned when reading or storing an input.
+#[derive(Debug)]
+pub enum InputError {
+    IO(io::Error),
+    MissingKey,
+    KeyFile(PathBuf, io::Error),
+    InvalidFormat,
+    WrongKey,
+}
+
+impl std::error::Error for InputError {}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::IO(e) => write!(f, "{e}"),
+            InputError::MissingKey => write!(
+                f,
+                "the input is encrypted, but neither `{KEY_ENV_VAR}` nor `input_key_file` is set."
+            ),
+            InputError::KeyFile(path, e) => {
+                write!(f, "could not read key file \"{}\": {e}", path.display())
+            }
+            InputError::InvalidFormat => write!(f, "the file is not a valid encrypted input."),
+            InputError::WrongKey => {
+                write!(f, "the input could not be decrypted, is the key correct?")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputError, Key, decrypt, encrypt, encrypted_path};
+    use std::path::Path;
+
+    const INPUT: &[u8] = b"L68\nL30\nR48\n";
+
+    #[test]
+    fn roundtrips_inputs() {
+        let key = Key::from_secret("secret");
+        let encrypted = encrypt(&key, INPUT);
+        assert!(encrypted.starts_with(b"AOC-ENC2"));
+        assert!(!encrypted.windows(INPUT.len()).any(|w| w == INPUT));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), INPUT);
+    }
+
+    #[test]
+    fn salts_every_file() {
+        let key = Key::from_secret("secret\n");
+        let (a, b) = (encrypt(&key, INPUT), encrypt(&key, INPUT));
+        assert_ne!(a, b);
+        assert_eq!(decrypt(&Key::from_secret("secret"), &a).unwrap(), INPUT);
+        assert_eq!(decrypt(&key, &b).unwrap(), INPUT);
+    }
+
+    #[test]
+    fn rejects_wrong_keys_and_tampering() {
+        let encrypted = encrypt(&Key::from_secret("secret"), INPUT);
+        assert!(matches!(
+            decrypt(&Key::from_secret("other"), &encrypted),
+            Err(InputError::WrongKey)
+        ));
+
+        let mut tampered = encrypted.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            decrypt(&Key::from_secret("secret"), &tampered),
+            Err(InputError::WrongKey)
+        ));
+
+        assert!(matches!(
+            decrypt(&Key::from_secret("secret"), INPUT),
+            Err(InputError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn appends_extension() {
+        assert_eq!(
+            encrypted_path(Path::new("data/inputs/01.txt")),
+            Path::new("data/inputs/01.txt.enc")
+        );
+    }
+}
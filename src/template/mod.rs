@@ -1,57 +1,197 @@
-use std::{env, fs};
+use std::env;
 
+pub mod alloc;
 pub mod aoc_cli;
+pub mod clock;
 pub mod commands;
+pub mod config;
+pub mod corpus;
+pub mod crosscheck;
+pub mod examples;
+pub mod fuzz;
+pub mod generators;
+pub mod inputs;
+pub mod paths;
 pub mod runner;
+pub mod solution;
+pub mod toml;
 
 pub use day::*;
+pub use solution::{Answer, IntoAnswer, NoAnswer, Overrides, Solution};
+pub use variant::*;
 
 mod day;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod variant;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// Encrypted files (e.g. `01.txt.enc`) are decrypted transparently, see [`inputs`].
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let filepath = cwd.join(paths::data_file(folder, day));
+    let f = inputs::read(&filepath);
+    f.unwrap_or_else(|e| panic!("could not open input file: {e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let filepath = cwd.join(paths::data_file_part(folder, day, part));
+    let f = inputs::read(&filepath);
+    f.unwrap_or_else(|e| panic!("could not open input file: {e}"))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// A solution is either a type implementing [`Solution`], e.g. `solution!(5 => Day05)`, or a pair
+/// of free `part_one` and `part_two` functions returning `Option<T>` or `Result<T, E>` (see
+/// [`IntoAnswer`]), e.g. `solution!(5)`. The latter are adapted to the [`Solution`] trait by a
+/// generated `Solver` type.
+///
+/// The generated `main` reads the puzzle input of the day, see [`runner::InputSource`] for the
+/// arguments that select a different input. With `--corpus`, the solution is checked against the
+/// regression [`corpus`] of the day instead.
+///
+/// For free functions, the optional second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Constants that differ between the puzzle input and the examples can be declared as parameters
+/// with the values of the puzzle input as defaults, e.g.
+/// `solution!(8, params { n_connections: usize = 1000 })`. This generates a `Params` struct that is
+/// passed to the free functions as `part_one(input: &str, params: &Params)`. Solutions implementing
+/// [`Solution`] build it in [`Solution::parse_with`] with `Params::from_overrides`. Parameters are
+/// overridden by the examples manifest or with `--param name=value`.
 #[macro_export]
 macro_rules! solution {
+    ($day:expr => $solution:ty, params { $($name:ident : $ty:ty = $default:expr),* $(,)? }) => {
+        $crate::solution!(@params $($name : $ty = $default),*);
+        $crate::solution!(@main $day, $solution, 1, 2);
+    };
+    ($day:expr, params { $($name:ident : $ty:ty = $default:expr),* $(,)? }) => {
+        $crate::solution!(@params $($name : $ty = $default),*);
+
+        /// Adapts the free `part_one` and `part_two` functions to the [`Solution`] trait.
+        ///
+        /// [`Solution`]: $crate::template::Solution
+        struct Solver;
+
+        impl $crate::template::Solution for Solver {
+            type Parsed<'a> = (&'a str, Params);
+            type Error = anyhow::Error;
+
+            fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+                Ok((input, Params::default()))
+            }
+
+            fn parse_with<'a>(
+                input: &'a str,
+                overrides: &$crate::template::Overrides,
+            ) -> Result<Self::Parsed<'a>, anyhow::Error> {
+                Ok((input, Params::from_overrides(overrides)?))
+            }
+
+            fn part_one(
+                (input, params): &Self::Parsed<'_>,
+            ) -> Result<$crate::template::Answer, Self::Error> {
+                $crate::template::IntoAnswer::into_answer(part_one(input, params))
+            }
+
+            fn part_two(
+                (input, params): &Self::Parsed<'_>,
+            ) -> Result<$crate::template::Answer, Self::Error> {
+                $crate::template::IntoAnswer::into_answer(part_two(input, params))
+            }
+        }
+
+        $crate::solution!(@main $day, Solver, 1, 2);
+    };
+    ($day:expr => $solution:ty) => {
+        $crate::solution!(@main $day, $solution, 1, 2);
+    };
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@compat $day, [part_one] [part_two], 1, 2);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@compat $day, [part_one] [], 1);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@compat $day, [] [part_two], 2);
+    };
+
+    (@compat $day:expr, [$($one:ident)?] [$($two:ident)?], $($part:expr),+) => {
+        /// Adapts the free `part_one` and `part_two` functions to the [`Solution`] trait.
+        ///
+        /// [`Solution`]: $crate::template::Solution
+        struct Solver;
+
+        impl $crate::template::Solution for Solver {
+            type Parsed<'a> = &'a str;
+            type Error = anyhow::Error;
+
+            fn parse(input: &str) -> Result<&str, Self::Error> {
+                Ok(input)
+            }
+
+            fn part_one(input: &&str) -> Result<$crate::template::Answer, Self::Error> {
+                ($crate::solution!(@part $($one)?))(input)
+            }
+
+            fn part_two(input: &&str) -> Result<$crate::template::Answer, Self::Error> {
+                ($crate::solution!(@part $($two)?))(input)
+            }
+        }
+
+        $crate::solution!(@main $day, Solver, $($part),+);
+    };
+
+    (@part $func:ident) => {
+        |input: &str| $crate::template::IntoAnswer::into_answer($func(input))
+    };
+    (@part) => {
+        |_: &str| Err($crate::template::NoAnswer.into())
+    };
+
+    (@params $($name:ident : $ty:ty = $default:expr),*) => {
+        /// Parameters of the solution. The defaults are the values for the puzzle input.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Params {
+            $(pub $name: $ty,)*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Self {
+                    $($name: $default,)*
+                }
+            }
+        }
+
+        impl Params {
+            /// The default parameters with the provided overrides applied.
+            pub fn from_overrides(
+                overrides: &$crate::template::Overrides,
+            ) -> Result<Self, anyhow::Error> {
+                overrides.check_keys(&[$(stringify!($name)),*])?;
+                #[allow(unused_mut)]
+                let mut params = Self::default();
+                $(
+                    if let Some(value) = overrides.get(stringify!($name))? {
+                        params.$name = value;
+                    }
+                )*
+                Ok(params)
+            }
+        }
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@main $day:expr, $solution:ty, $($part:expr),+) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -59,10 +199,113 @@ macro_rules! solution {
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(all(feature = "count-alloc", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::Counting = $crate::template::alloc::Counting;
+
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            run::<$solution>(DAY, &[$($part),+]);
+        }
+    };
+}
+
+/// Includes the tests generated from the examples manifest of the day, one `#[test]` per example.
+/// See [`examples`] for the format of the manifest.
+///
+/// The optional parameter is the type implementing [`Solution`], it defaults to the `Solver`
+/// generated by [`solution!`] for free functions.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        $crate::example_tests!(Solver);
+    };
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod example_tests {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::template::runner::assert_example;
+
+            type ExampleSolution = $solution;
+
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/examples/",
+                env!("CARGO_BIN_NAME"),
+                ".rs"
+            ));
+        }
+    };
+}
+
+/// Checks the solution against the slow [`reference`](crate::reference) solution of the day on
+/// many small inputs from the [`generators`], see [`crosscheck`]. The first input on which they
+/// disagree is shrunk and reported.
+///
+/// The first parameter names both the generator and the reference, e.g. `reference_tests!(Day09)`.
+/// The solution defaults to the `Solver` generated by [`solution!`], another type is given with
+/// `reference_tests!(Day05 => Day05)`, followed by parameters for the small inputs if needed. The
+/// parts to check can be restricted with `parts = [1]`.
+#[macro_export]
+macro_rules! reference_tests {
+    ($day:ident $(, $key:ident = $value:literal)* $(,)?) => {
+        $crate::reference_tests!(@tests $day, Solver, [1, 2], [$($key = $value),*]);
+    };
+    ($day:ident, parts = [$($part:literal),+] $(, $key:ident = $value:literal)* $(,)?) => {
+        $crate::reference_tests!(@tests $day, Solver, [$($part),+], [$($key = $value),*]);
+    };
+    ($day:ident => $solution:ty $(, $key:ident = $value:literal)* $(,)?) => {
+        $crate::reference_tests!(@tests $day, $solution, [1, 2], [$($key = $value),*]);
+    };
+
+    (@tests $day:ident, $solution:ty, [$($part:literal),+], [$($key:ident = $value:literal),*]) => {
+        #[cfg(test)]
+        mod reference_tests {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::template::crosscheck::Crosscheck;
+
+            #[test]
+            fn agrees_with_reference() {
+                #[allow(unused_mut)]
+                let mut check = Crosscheck::small();
+                $(check.overrides.insert(stringify!($key), stringify!($value));)*
+                let result = check.run::<
+                    $crate::template::generators::$day,
+                    $solution,
+                    $crate::reference::$day,
+                >(&[$($part),+]);
+                if let Err(mismatch) = result {
+                    panic!("{mismatch}");
+                }
+            }
+        }
+    };
+}
+
+/// Fuzzes the parser of the day with mutations of its examples and of a generated input, see
+/// [`fuzz`]. The parser is any function `fn(&str) -> Result<T, E>`, e.g. `fuzz_tests!(parse_input)`
+/// or `fuzz_tests!(Day05::parse)`. It may reject malformed input with an error, but must not panic.
+#[macro_export]
+macro_rules! fuzz_tests {
+    ($parse:expr) => {
+        #[cfg(test)]
+        mod fuzz_tests {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::template::fuzz::Fuzz;
+
+            #[test]
+            fn parser_does_not_panic() {
+                let fuzz = Fuzz::default();
+                let parse = |input: &str| {
+                    let _ = ($parse)(input);
+                };
+                if let Err(crash) = fuzz.run(&fuzz.corpus(super::DAY), parse) {
+                    panic!("{crash}");
+                }
+            }
         }
     };
 }
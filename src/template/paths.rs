@@ -0,0 +1,70 @@
+/// Locations of the files that are generated for and read by the solutions.
+/// Data locations are read from the project [`Config`].
+use crate::template::config::Config;
+use crate::template::{Bin, Day};
+
+const BIN_DIR: &str = "./src/bin";
+
+/// Directory containing the solution binaries.
+pub fn bin_dir() -> String {
+    BIN_DIR.into()
+}
+
+/// Source file of a solution binary.
+pub fn bin(bin: &Bin) -> String {
+    format!("{BIN_DIR}/{bin}.rs")
+}
+
+/// Directory of a data folder, e.g. `data/inputs`.
+pub fn data_dir(folder: &str) -> String {
+    Config::get().folder(folder).display().to_string()
+}
+
+/// A text file of a data folder, e.g. `data/examples/01.txt`.
+pub fn data_file(folder: &str, day: Day) -> String {
+    format!("{}/{day}.txt", data_dir(folder))
+}
+
+/// A text file of a data folder with a part suffix, e.g. `data/examples/01-2.txt`.
+pub fn data_file_part(folder: &str, day: Day, part: u8) -> String {
+    format!("{}/{day}-{part}.txt", data_dir(folder))
+}
+
+/// The puzzle input of a day.
+pub fn input(day: Day) -> String {
+    data_file("inputs", day)
+}
+
+/// The directory holding the regression corpus of a day, e.g. `data/inputs/01/`.
+pub fn corpus(day: Day) -> String {
+    format!("{}/{day}", data_dir("inputs"))
+}
+
+/// The (first) example of a day.
+pub fn example(day: Day) -> String {
+    data_file("examples", day)
+}
+
+/// The examples manifest of a day, see [`crate::template::examples`].
+pub fn examples_manifest(day: Day) -> String {
+    format!("{}/{day}.toml", data_dir("examples"))
+}
+
+/// The puzzle description of a day.
+pub fn puzzle(day: Day) -> String {
+    format!("{}/{day}.md", data_dir("puzzles"))
+}
+
+/// The stored benchmark results.
+pub fn timings() -> String {
+    Config::get()
+        .data_dir
+        .join("timings.json")
+        .display()
+        .to_string()
+}
+
+/// The readme containing the benchmark table.
+pub fn readme() -> String {
+    Config::get().readme.display().to_string()
+}
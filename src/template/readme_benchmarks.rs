@@ -1,9 +1,10 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::path::{Component, Path, PathBuf};
+use std::{env, fs, io};
 
-use crate::template::Day;
 use crate::template::timings::Timings;
+use crate::template::{Bin, paths};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +26,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,26 +48,80 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Link to `target` from the directory of `readme`, both given relative to the working
+/// directory or as absolute paths.
+fn link(readme: &Path, target: &Path) -> String {
+    let absolute = |path: &Path| {
+        let mut normal = PathBuf::new();
+        for component in env::current_dir()
+            .unwrap_or_default()
+            .join(path)
+            .components()
+        {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    normal.pop();
+                }
+                component => normal.push(component),
+            }
+        }
+        normal
+    };
+    let from = absolute(readme.parent().unwrap_or(Path::new("")));
+    let target = absolute(target);
+
+    let common = from
+        .components()
+        .zip(target.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut link = match from.components().count() - common {
+        0 => ".".to_string(),
+        up => vec![".."; up].join("/"),
+    };
+    for component in target.components().skip(common) {
+        link.push('/');
+        link.push_str(&component.as_os_str().to_string_lossy());
+    }
+    link
+}
+
+fn construct_table(prefix: &str, timings: Timings, total_millis: f64, readme: &Path) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // The allocations column is only shown if any day was timed with allocation statistics.
+    let with_alloc = timings
+        .data
+        .iter()
+        .any(|t| t.alloc_1.is_some() || t.alloc_2.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+    if with_alloc {
+        lines.push("| Day | Part 1 | Part 2 | Allocations (part 1 / part 2) |".into());
+        lines.push("| :---: | :---: | :---:  | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let path = link(readme, Path::new(&paths::bin(&Bin::main(timing.day))));
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+        if with_alloc {
+            line += &format!(
+                " `{}` / `{}` |",
+                timing.alloc_1.unwrap_or_else(|| "-".into()),
+                timing.alloc_2.unwrap_or_else(|| "-".into())
+            );
+        }
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -81,25 +131,32 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    readme: &Path,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, readme);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let path = paths::readme();
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+    update_content(&mut readme, timings, total_millis, Path::new(&path))?;
+    fs::write(&path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
+    use std::{env, path::Path};
+
+    use super::{MARKER, link, update_content};
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
@@ -110,18 +167,24 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    alloc_1: None,
+                    alloc_2: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    alloc_1: None,
+                    alloc_2: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    alloc_1: None,
+                    alloc_2: None,
                 },
             ],
         }
@@ -131,28 +194,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Path::new("README.md")).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Path::new("README.md")).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Path::new("README.md")).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Path::new("README.md")).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Path::new("README.md")).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +223,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Path::new("README.md")).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -180,4 +243,37 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_allocation_column() {
+        let mut timings = get_mock_timings();
+        timings.data[0].alloc_1 = Some("0 allocs, 0 B, peak 0 B".into());
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, Path::new("README.md")).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Part 1 | Part 2 | Allocations (part 1 / part 2) |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | `0 allocs, 0 B, peak 0 B` / `-` |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` | `-` / `-` |"
+        );
+    }
+
+    #[test]
+    fn links_relative_to_the_readme() {
+        let bin = Path::new("./src/bin/01.rs");
+        assert_eq!(link(Path::new("./README.md"), bin), "./src/bin/01.rs");
+        assert_eq!(link(Path::new("docs/README.md"), bin), "../src/bin/01.rs");
+        assert_eq!(link(Path::new("./src/README.md"), bin), "./bin/01.rs");
+        let outside = env::current_dir().unwrap().join("../other/README.md");
+        let project = env::current_dir().unwrap();
+        let name = project.file_name().unwrap().to_string_lossy();
+        assert_eq!(link(&outside, bin), format!("../{name}/src/bin/01.rs"));
+    }
 }
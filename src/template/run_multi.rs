@@ -1,13 +1,18 @@
 use std::{collections::HashSet, io};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Bin, Day};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    count_alloc: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
@@ -24,7 +29,9 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output =
+                child_commands::run_solution(&Bin::main(day), is_timed, is_release, count_alloc)
+                    .unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
@@ -59,37 +66,41 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{ANSI_BOLD, ANSI_RESET, Bin, Day};
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run a solution bin, i.e. the main solution of a day or one of its variants. With
+    /// `count_alloc`, the bin is built with the counting allocator to report its allocations.
+    pub fn run_solution(
+        bin: &Bin,
+        is_timed: bool,
+        is_release: bool,
+        count_alloc: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !bin.exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = bin.to_string();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
         }
 
+        if count_alloc {
+            args.extend(["--features", "count-alloc"]);
+        }
+
         if is_timed {
             // mirror `--time` flag to child invocations.
             args.push("--");
@@ -134,6 +145,8 @@ pub mod child_commands {
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            alloc_1: None,
+            alloc_2: None,
         };
 
         output
@@ -149,13 +162,15 @@ pub mod child_commands {
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((part, timing_str, nanos, parse_alloc(l)))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, timing_str, nanos, alloc)| {
                 if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
+                    timings.alloc_1 = alloc.map(Into::into);
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
+                    timings.alloc_2 = alloc.map(Into::into);
                 }
 
                 timings.total_nanos += nanos;
@@ -164,6 +179,43 @@ pub mod child_commands {
         timings
     }
 
+    /// Extract the answers of both parts from the output of a solution bin.
+    /// Parts that did not produce an answer are returned as `None`.
+    pub fn parse_answers(output: &[String]) -> [Option<String>; 2] {
+        let mut answers = [None, None];
+
+        for (i, line) in output.iter().enumerate() {
+            let Some(part) = ["Part 1: ", "Part 2: "]
+                .iter()
+                .position(|prefix| line.starts_with(prefix))
+            else {
+                continue;
+            };
+
+            if line.contains('▼') {
+                // multi-line results are printed below the part header.
+                let result = output[i + 1..]
+                    .iter()
+                    .take_while(|l| !l.starts_with("Part "))
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                answers[part] = Some(result);
+            } else if let Some((_, rest)) = line.split_once(ANSI_BOLD)
+                && let Some((result, _)) = rest.split_once(ANSI_RESET)
+            {
+                answers[part] = Some(result.to_string());
+            }
+        }
+
+        answers
+    }
+
+    /// The allocation statistics printed after the timing, e.g. `(1.0ms @ 10 samples) [..]`.
+    fn parse_alloc(line: &str) -> Option<&str> {
+        line.rsplit_once(" samples) [")?.1.strip_suffix(']')
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -205,7 +257,7 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_answers, parse_exec_time};
 
         use crate::day;
 
@@ -222,6 +274,22 @@ pub mod child_commands {
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
             assert_eq!(res.part_1.unwrap(), "74.13ns");
             assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.alloc_1, None);
+        }
+
+        #[test]
+        fn parses_allocation_stats() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples) [0 allocs, 0 B, peak 0 B]".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples) [12 allocs, 1.5 KiB, peak 1.0 KiB]"
+                        .into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap(), "74.13ns");
+            assert_eq!(res.alloc_1.unwrap(), "0 allocs, 0 B, peak 0 B");
+            assert_eq!(res.alloc_2.unwrap(), "12 allocs, 1.5 KiB, peak 1.0 KiB");
         }
 
         #[test]
@@ -253,5 +321,25 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_answers() {
+            let res = parse_answers(&[
+                "Part 1: \x1b[1m1227775554\x1b[0m (13.8µs @ 10000 samples)".into(),
+                "Part 2: ✖             ".into(),
+            ]);
+            assert_eq!(res, [Some("1227775554".into()), None]);
+        }
+
+        #[test]
+        fn parses_multi_line_answers() {
+            let res = parse_answers(&[
+                "Part 1: ▼  (1.0ms @ 10 samples)".into(),
+                "#.#".into(),
+                ".#.".into(),
+                "Part 2: \x1b[1m4\x1b[0m (1.0ms @ 10 samples)".into(),
+            ]);
+            assert_eq!(res, [Some("#.#\n.#.".into()), Some("4".into())]);
+        }
     }
 }
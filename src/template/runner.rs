@@ -1,23 +1,255 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
+use std::io::{Read, Write, stdin, stdout};
+use std::path::PathBuf;
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::alloc;
+use crate::template::corpus::{self, Verdict};
+use crate::template::solution::{Answer, NoAnswer, Overrides, Solution, solve, solve_with};
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Day, aoc_cli, inputs, paths, read_file, read_file_part,
+};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// The input a solution binary is run against, selected by its arguments:
+///  - `--input <path>` reads an arbitrary file.
+///  - `--example [N]` reads the example of the day, or the part specific example `NN-N.txt`.
+///  - `-` reads from stdin.
+///
+/// Without any of these, the puzzle input of the day is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Inputs,
+    Example(Option<u8>),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut source = InputSource::Inputs;
+        let mut args = args.iter().peekable();
+
+        while let Some(arg) = args.next() {
+            let next = match arg.as_str() {
+                "--input" => {
+                    let path = args.next().ok_or("missing path after `--input`")?;
+                    InputSource::File(path.into())
+                }
+                "--example" => {
+                    let part = args.next_if(|arg| arg.parse::<u8>().is_ok());
+                    InputSource::Example(part.map(|part| part.parse().unwrap()))
+                }
+                "-" => InputSource::Stdin,
+                _ => continue,
+            };
+
+            if source != InputSource::Inputs {
+                return Err("only one of `--input`, `--example` and `-` can be used.".into());
+            }
+            source = next;
+        }
+
+        Ok(source)
+    }
+
+    /// Arguments that select this input source, used to forward it to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Inputs => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(part)) => vec!["--example".into(), part.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["-".into()],
+        }
+    }
+
+    pub fn read(&self, day: Day) -> String {
+        let result = match self {
+            InputSource::Inputs => return read_file("inputs", day),
+            InputSource::Example(None) => return read_file("examples", day),
+            InputSource::Example(Some(part)) => return read_file_part("examples", day, *part),
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.unwrap_or_else(|e| {
+            eprintln!("Could not read input: {e}");
+            process::exit(1);
+        })
+    }
+}
+
+/// Parses the parameter overrides passed to a solution binary as `--param name=value`.
+pub fn overrides_from_args(args: &[String]) -> Result<Overrides, String> {
+    let mut overrides = Overrides::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg != "--param" {
+            continue;
+        }
+        let param = args.next().ok_or("missing `name=value` after `--param`")?;
+        let (key, value) = param
+            .split_once('=')
+            .ok_or_else(|| format!("invalid parameter `{param}`, expected `name=value`."))?;
+        overrides.insert(key.trim(), value.trim());
+    }
+
+    Ok(overrides)
+}
+
+/// Reads the input selected by the arguments of the solution binary, see [`InputSource`].
+pub fn read_input(day: Day) -> String {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match InputSource::from_args(&args) {
+        Ok(source) => source.read(day),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Entry point of a solution binary: runs the `parts` of a solution against the selected input
+/// and parameters, or against the regression corpus of the day if `--corpus` is passed.
+pub fn run<S: Solution>(day: Day, parts: &[u8]) {
+    if env::args().any(|x| x == "--corpus") {
+        if !run_corpus::<S>(day, parts) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let overrides = overrides_from_args(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    run_day::<S>(&read_input(day), day, parts, &overrides);
+}
+
+/// Runs the `parts` of a solution against every input of the corpus of a day, reporting the
+/// correctness and timing of each. Returns whether no part failed or gave a wrong answer.
+pub fn run_corpus<S: Solution>(day: Day, parts: &[u8]) -> bool {
+    let entries = match corpus::load(day) {
+        Ok(entries) if !entries.is_empty() => entries,
+        Ok(_) => {
+            println!("No corpus found in \"{}\".", paths::corpus(day));
+            return true;
+        }
+        Err(e) => {
+            eprintln!("Could not load corpus: {e}");
+            return false;
+        }
+    };
+
+    let mut failures = 0;
+    for entry in &entries {
+        println!("{ANSI_BOLD}{}{ANSI_RESET}", entry.name);
+
+        let input = match entry.read() {
+            Ok(input) => input,
+            Err(e) => {
+                println!("  could not read input: {e}");
+                failures += 1;
+                continue;
+            }
+        };
+
+        let mut failed = false;
+        for &part in parts {
+            let timer = Instant::now();
+            let result = solve::<S>(&input, part);
+            let duration = timer.elapsed();
+
+            let answer = result.as_ref().ok().map(ToString::to_string);
+            let expected = entry.expected[usize::from(part) - 1].as_deref();
+            let verdict = Verdict::new(answer.as_deref(), expected);
+            failed |= verdict.is_failure();
+
+            let answer = answer.as_deref().unwrap_or("✖");
+            match verdict {
+                Verdict::Correct => println!("  Part {part}: {answer} ✔ ({duration:.1?})"),
+                Verdict::Wrong { expected } => {
+                    println!("  Part {part}: {answer} ✖ expected {expected} ({duration:.1?})")
+                }
+                Verdict::Unknown => {
+                    println!("  Part {part}: {answer} ? no expected answer ({duration:.1?})")
+                }
+                Verdict::Failed => println!("  Part {part}: ✖ ({duration:.1?})"),
+            }
+            if let Err(err) = &result {
+                print_error(err);
+            }
+        }
+        failures += usize::from(failed);
+    }
+
+    println!("---");
+    if failures == 0 {
+        println!("🎄 All {} inputs passed.", entries.len());
+    } else {
+        println!("❌ {failures} of {} inputs failed.", entries.len());
+    }
+    failures == 0
+}
+
+/// Solves an example with the provided parameters and asserts its answer.
+/// Used by the tests generated from the examples manifest, see [`crate::example_tests`].
+pub fn assert_example<S: Solution>(file: &str, part: u8, expected: &str, params: &[(&str, &str)]) {
+    let path = PathBuf::from(paths::data_dir("examples")).join(file);
+    let input = inputs::read(&path)
+        .unwrap_or_else(|e| panic!("could not open example file \"{}\": {e}", path.display()));
+    let overrides: Overrides = params.iter().copied().collect();
+
+    match solve_with::<S>(&input, part, &overrides) {
+        Ok(answer) => assert_eq!(
+            answer.to_string(),
+            expected,
+            "wrong answer for part {part} of \"{file}\""
+        ),
+        Err(err) => panic!("part {part} of \"{file}\" failed: {err:?}"),
+    }
+}
+
+/// Runs the provided `parts` of a solution. Parsing is timed as part of each part.
+pub fn run_day<S: Solution>(input: &str, day: Day, parts: &[u8], overrides: &Overrides) {
+    for &part in parts {
+        run_part(
+            |input| solve_with::<S>(input, part, overrides),
+            input,
+            day,
+            part,
+        );
+    }
+}
+
+pub fn run_part<I: Copy>(
+    func: impl Fn(I) -> Result<Answer, anyhow::Error>,
+    input: I,
+    day: Day,
+    part: u8,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
+    let (result, duration, samples, stats) =
         run_timed(func, input, |result| print_result(result, &part_str, ""));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let stats_str = stats.map_or_else(String::new, |stats| format!(" [{stats}]"));
+    print_result(
+        &result,
+        &part_str,
+        &(format_duration(&duration, samples) + &stats_str),
+    );
 
-    if let Some(result) = result {
+    if let Ok(result) = result {
         submit_result(result, day, part);
     }
 }
@@ -25,17 +257,20 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The allocations of the first run are counted if the counting allocator is installed, see
+/// [`alloc`].
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<alloc::Stats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, stats) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        alloc::measure(|| func(input))
     };
     let base_time = timer.elapsed();
 
@@ -47,7 +282,7 @@ fn run_timed<I: Copy, T>(
         (base_time, 1)
     };
 
-    (result, run.0, run.1)
+    (result, run.0, run.1, stats)
 }
 
 fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
@@ -90,11 +325,11 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(result: &Result<Answer, anyhow::Error>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -114,22 +349,35 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Err(err) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
                 print!("\r");
                 println!("{part}: ✖             ");
+                print_error(err);
             }
         }
     }
 }
 
+/// Print an error with its chain of causes. A missing answer is not considered an error.
+fn print_error(err: &anyhow::Error) {
+    if err.is::<NoAnswer>() {
+        return;
+    }
+
+    println!("  error: {err}");
+    for cause in err.chain().skip(1) {
+        println!("  caused by: {cause}");
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+fn submit_result(
+    result: Answer,
     day: Day,
     part: u8,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
@@ -155,6 +403,17 @@ fn submit_result<T: Display>(
         return None;
     }
 
+    let args: Vec<String> = args.into_iter().skip(1).collect();
+    if InputSource::from_args(&args) != Ok(InputSource::Inputs) {
+        eprintln!("Not submitting, the solution was not run against the puzzle input.");
+        return None;
+    }
+
+    if !overrides_from_args(&args).is_ok_and(|overrides| overrides.is_empty()) {
+        eprintln!("Not submitting, the solution was run with overridden parameters.");
+        return None;
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -165,3 +424,67 @@ fn submit_result<T: Display>(
     println!("Submitting result via aoc-cli...");
     Some(aoc_cli::submit(day, part, &result.to_string()))
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputSource, overrides_from_args};
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<InputSource, String> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        InputSource::from_args(&args)
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse(&["--time"]), Ok(InputSource::Inputs));
+        assert_eq!(
+            parse(&["--input", "other/01.txt", "--time"]),
+            Ok(InputSource::File(PathBuf::from("other/01.txt")))
+        );
+        assert_eq!(
+            parse(&["--example", "--submit", "1"]),
+            Ok(InputSource::Example(None))
+        );
+        assert_eq!(
+            parse(&["--example", "2"]),
+            Ok(InputSource::Example(Some(2)))
+        );
+        assert_eq!(parse(&["-"]), Ok(InputSource::Stdin));
+    }
+
+    #[test]
+    fn rejects_invalid_input_sources() {
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--example", "-"]).is_err());
+    }
+
+    #[test]
+    fn parses_overrides() {
+        let args: Vec<String> = ["--param", "n=10", "--example", "--param", "name = x"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let overrides = overrides_from_args(&args).unwrap();
+        assert_eq!(overrides.get::<u8>("n").unwrap(), Some(10));
+        assert_eq!(overrides.get::<String>("name").unwrap(), Some("x".into()));
+
+        assert!(overrides_from_args(&["--param".into()]).is_err());
+        assert!(overrides_from_args(&["--param".into(), "n".into()]).is_err());
+    }
+
+    #[test]
+    fn roundtrips_arguments() {
+        for source in [
+            InputSource::Inputs,
+            InputSource::Example(None),
+            InputSource::Example(Some(2)),
+            InputSource::File(PathBuf::from("input.txt")),
+            InputSource::Stdin,
+        ] {
+            assert_eq!(InputSource::from_args(&source.to_args()), Ok(source));
+        }
+    }
+}
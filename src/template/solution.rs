@@ -0,0 +1,303 @@
+/// The interface between a day's solution and the runner.
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A solution of a day. The input is parsed once per run of a part, and both parts receive the
+/// parsed input. Failures are reported as errors with their full chain of causes.
+///
+/// Solutions written as a pair of free `part_one` / `part_two` functions are adapted to this trait
+/// by the [`solution!`](crate::solution) macro.
+pub trait Solution {
+    /// The parsed puzzle input, which may borrow from the raw input.
+    type Parsed<'a>;
+    type Error: Into<anyhow::Error>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error>;
+
+    /// Parses the input with overridden parameters, e.g. a smaller size for an example.
+    /// Solutions without parameters reject any override, so a mistyped one is not ignored.
+    fn parse_with<'a>(
+        input: &'a str,
+        overrides: &Overrides,
+    ) -> Result<Self::Parsed<'a>, anyhow::Error> {
+        overrides.check_keys(&[])?;
+        Self::parse(input).map_err(Into::into)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Answer, Self::Error>;
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Answer, Self::Error>;
+}
+
+/// Parses `input` and solves one `part` of it.
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<Answer, anyhow::Error> {
+    solve_with::<S>(input, part, &Overrides::default())
+}
+
+/// Parses `input` with overridden parameters and solves one `part` of it.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    part: u8,
+    overrides: &Overrides,
+) -> Result<Answer, anyhow::Error> {
+    let parsed = S::parse_with(input, overrides)?;
+    let answer = match part {
+        1 => S::part_one(&parsed),
+        2 => S::part_two(&parsed),
+        _ => return Err(anyhow::anyhow!("invalid part {part}")),
+    };
+    answer.map_err(Into::into)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Named parameters of a solution given as strings, e.g. `n_connections = 10` for the example of
+/// day 8, which uses fewer connections than the puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overrides(BTreeMap<String, String>);
+
+impl Overrides {
+    /// Parses the value of a parameter, `None` if it is not overridden.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, anyhow::Error>
+    where
+        T::Err: Display,
+    {
+        self.0
+            .get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid value `{value}` for `{key}`: {e}"))
+            })
+            .transpose()
+    }
+
+    /// Fails if a parameter is overridden that is not one of `known`.
+    pub fn check_keys(&self, known: &[&str]) -> Result<(), anyhow::Error> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            Some(key) if known.is_empty() => Err(anyhow::anyhow!(
+                "unknown parameter `{key}`, the solution has none"
+            )),
+            Some(key) => Err(anyhow::anyhow!(
+                "unknown parameter `{key}`, expected one of `{}`",
+                known.join("`, `")
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Overrides {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The answer to a part of a puzzle.
+///
+/// Numbers compare by value regardless of their sign, i.e. `Answer::from(3_u64) == Answer::from(3)`.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+impl Answer {
+    fn as_number(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(n) => i128::try_from(*n).ok(),
+            Answer::Signed(n) => Some(*n),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => false,
+            (a, b) => a.as_number().is_some() && a.as_number() == b.as_number(),
+        }
+    }
+}
+
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a.partial_cmp(b),
+            (Answer::Text(a), Answer::Text(b)) => a.partial_cmp(b),
+            (a, b) => a.as_number()?.partial_cmp(&b.as_number()?),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $inner:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(<$inner>::try_from(value).unwrap())
+                }
+            }
+        )+
+    };
+}
+
+impl_answer_from!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+impl_answer_from!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The return value of a free `part_one` / `part_two` function adapted by
+/// [`solution!`](crate::solution): `Option<T>`, where `None` means there is no answer, or
+/// `Result<T, E>` for parts that can fail, e.g. on malformed input.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, anyhow::Error>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Answer, anyhow::Error> {
+        self.map(Into::into).ok_or_else(|| NoAnswer.into())
+    }
+}
+
+impl<T: Into<Answer>, E: Into<anyhow::Error>> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, anyhow::Error> {
+        self.map(Into::into).map_err(Into::into)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The error of a part that has no answer (yet), i.e. a free function that returned `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl std::error::Error for NoAnswer {}
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no answer")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, NoAnswer, Overrides, Solution, solve, solve_with};
+    use anyhow::{Context, anyhow};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed<'a> = Vec<&'a str>;
+        type Error = anyhow::Error;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_one(lines: &Self::Parsed<'_>) -> Result<Answer, Self::Error> {
+            let mut sum = 0_i64;
+            for line in lines {
+                sum += line
+                    .parse::<i64>()
+                    .with_context(|| format!("invalid number `{line}`"))?;
+            }
+            Ok(sum.into())
+        }
+
+        fn part_two(_: &Self::Parsed<'_>) -> Result<Answer, Self::Error> {
+            Err(anyhow!(NoAnswer))
+        }
+    }
+
+    #[test]
+    fn solves_parts() {
+        assert_eq!(solve::<Sum>("1\n2\n3", 1).unwrap(), 6.into());
+        assert!(solve::<Sum>("1", 2).unwrap_err().is::<NoAnswer>());
+        assert!(solve::<Sum>("1", 3).is_err());
+    }
+
+    #[test]
+    fn keeps_error_chain() {
+        let err = solve::<Sum>("1\nx", 1).unwrap_err();
+        let chain: Vec<String> = err.chain().map(ToString::to_string).collect();
+        assert_eq!(chain[0], "invalid number `x`");
+        assert_eq!(chain.len(), 2);
+    }
+
+    #[test]
+    fn parses_overrides() {
+        let overrides: Overrides = [("n", "10"), ("name", "x")].into_iter().collect();
+        assert_eq!(overrides.get::<usize>("n").unwrap(), Some(10));
+        assert_eq!(overrides.get::<usize>("missing").unwrap(), None);
+        assert!(overrides.get::<usize>("name").is_err());
+        assert!(overrides.check_keys(&["n", "name"]).is_ok());
+        assert!(overrides.check_keys(&["n"]).is_err());
+        assert!(overrides.check_keys(&[]).is_err());
+    }
+
+    #[test]
+    fn rejects_overrides_without_parameters() {
+        let overrides: Overrides = [("n", "10")].into_iter().collect();
+        let err = solve_with::<Sum>("1", 1, &overrides).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown parameter `n`, the solution has none"
+        );
+        assert!(solve_with::<Sum>("1", 1, &Overrides::default()).is_ok());
+    }
+
+    #[test]
+    fn compares_answers_by_value() {
+        assert_eq!(Answer::from(3_u64), Answer::from(3_i32));
+        assert_ne!(Answer::from(3_u64), Answer::from(-3_i32));
+        assert_ne!(Answer::from(3_u64), Answer::from("3"));
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+        assert_ne!(Answer::from(u128::MAX), Answer::from(-1_i8));
+        assert!(Answer::from(-1_i64) < Answer::from(2_usize));
+        assert_eq!(Answer::from("a\nb").to_string(), "a\nb");
+    }
+}
@@ -1,9 +1,7 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::{Day, paths};
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,6 +10,9 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Allocation statistics of the parts, only recorded with `cargo time --alloc`.
+    pub alloc_1: Option<String>,
+    pub alloc_2: Option<String>,
 }
 
 /// Represents benchmark times for a set of days.
@@ -25,13 +26,13 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(paths::timings())?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(paths::timings())
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,10 +52,21 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
+    /// Returns a copy of `self` without the timing of `day`.
+    pub fn remove(&self, day: Day) -> Self {
+        Timings {
+            data: self.data.iter().filter(|t| t.day != day).cloned().collect(),
+        }
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.data.iter().any(|t| t.day == day)
+    }
+
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
@@ -133,6 +145,13 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        // Allocation statistics are optional, so timings without them stay unchanged.
+        for (key, alloc) in [("alloc_1", &value.alloc_1), ("alloc_2", &value.alloc_2)] {
+            if let Some(alloc) = alloc {
+                map.insert(key.into(), JsonValue::String(alloc.clone()));
+            }
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,11 +185,15 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let alloc = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            alloc_1: alloc("alloc_1"),
+            alloc_2: alloc("alloc_2"),
         })
     }
 }
@@ -191,18 +214,24 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    alloc_1: None,
+                    alloc_2: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    alloc_1: None,
+                    alloc_2: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    alloc_1: None,
+                    alloc_2: None,
                 },
             ],
         }
@@ -221,6 +250,23 @@ mod tests {
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.alloc_1, None);
+        }
+
+        #[test]
+        fn handles_allocation_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000, "alloc_1": "3 allocs, 96 B, peak 64 B" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data[0].clone();
+            assert_eq!(
+                timing.alloc_1,
+                Some("3 allocs, 96 B, peak 64 B".to_string())
+            );
+            assert_eq!(timing.alloc_2, None);
+
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].alloc_1, timing.alloc_1);
         }
 
         #[test]
@@ -282,10 +328,12 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    alloc_1: None,
+                    alloc_2: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -296,10 +344,12 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    alloc_1: None,
+                    alloc_2: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -310,10 +360,12 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    alloc_1: None,
+                    alloc_2: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -334,6 +386,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    alloc_1: None,
+                    alloc_2: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +408,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    alloc_1: None,
+                    alloc_2: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -381,4 +437,24 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod remove {
+        use crate::day;
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn removes_present_day() {
+            let timings = get_mock_timings().remove(day!(2));
+            assert_eq!(timings.data.len(), 2);
+            assert!(!timings.contains(day!(2)));
+            assert!(timings.contains(day!(1)));
+        }
+
+        #[test]
+        fn ignores_missing_day() {
+            let timings = get_mock_timings().remove(day!(3));
+            assert_eq!(timings.data.len(), 3);
+        }
+    }
 }
@@ -0,0 +1,87 @@
+/// TOML documents like the project configuration, example manifests and corpus answers, parsed
+/// with the `toml` crate.
+///
+/// This module only depends on `std` and `toml` so it can be shared with the build script.
+pub use ::toml::de::Error as TomlError;
+pub use ::toml::{Table, Value};
+
+/// Looks up a nested value by a dotted path like `package.metadata.aoc`.
+pub fn get<'a>(table: &'a Table, path: &str) -> Option<&'a Value> {
+    let mut keys = path.split('.');
+    let mut value = table.get(keys.next()?)?;
+    for key in keys {
+        value = value.as_table()?.get(key)?;
+    }
+    Some(value)
+}
+
+/// Parses a TOML document into its root table.
+pub fn parse(input: &str) -> Result<Table, TomlError> {
+    input.parse()
+}
+
+/// Displays scalars without quotes, e.g. `"abc"` as `abc`.
+pub fn plain(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Value, get, parse, plain};
+
+    #[test]
+    fn parses_tables_and_scalars() {
+        let doc = parse(
+            r#"
+            # comment
+            name = "advent_of_code" # trailing comment
+            [package.metadata.aoc]
+            year = 2_025
+            data_dir = 'data # not a comment'
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(doc["name"], Value::String("advent_of_code".into()));
+        let aoc = get(&doc, "package.metadata.aoc")
+            .unwrap()
+            .as_table()
+            .unwrap();
+        assert_eq!(aoc["year"], Value::Integer(2025));
+        assert_eq!(plain(&aoc["data_dir"]), "data # not a comment");
+        assert_eq!(plain(&aoc["year"]), "2025");
+    }
+
+    #[test]
+    fn parses_multi_line_values() {
+        let doc = parse(
+            r#"
+            [dependencies]
+            foo = { features = [
+                "a",
+            ] }
+            [package]
+            description = """
+            two
+            lines"""
+            "#,
+        )
+        .unwrap();
+
+        let features = get(&doc, "dependencies.foo.features").unwrap();
+        assert_eq!(features.as_array().unwrap().len(), 1);
+        assert!(plain(get(&doc, "package.description").unwrap()).contains('\n'));
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        assert!(parse("a = 1\na = 2").is_err());
+        assert!(parse("[table").is_err());
+        assert!(parse("a = what").is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::Display;
+use std::{fs, path::Path};
+
+use crate::template::{Day, paths};
+
+/// A solution binary for a day: either the main `NN.rs` solution or one of its
+/// alternative implementations living in `NN_<variant>.rs`.
+///
+/// # Display
+/// This value displays as the name of the cargo binary.
+///
+/// ```
+/// # use advent_of_code::template::{Bin, Day};
+/// let day = Day::new(1).unwrap();
+/// assert_eq!(Bin::main(day).to_string(), "01");
+/// assert_eq!(Bin::variant(day, "no_alloc").to_string(), "01_no_alloc");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bin {
+    pub day: Day,
+    pub variant: Option<String>,
+}
+
+impl Bin {
+    /// The main solution binary of a day.
+    pub fn main(day: Day) -> Self {
+        Self { day, variant: None }
+    }
+
+    /// A named variant of a day's solution.
+    pub fn variant(day: Day, name: &str) -> Self {
+        Self {
+            day,
+            variant: Some(name.into()),
+        }
+    }
+
+    /// Creates either the main binary or a variant, depending on `variant`.
+    pub fn new(day: Day, variant: Option<&str>) -> Self {
+        variant.map_or_else(|| Self::main(day), |name| Self::variant(day, name))
+    }
+
+    /// Path of the source file of this binary.
+    pub fn path(&self) -> String {
+        paths::bin(self)
+    }
+
+    /// Returns `true` if the source file of this binary has been scaffolded.
+    pub fn exists(&self) -> bool {
+        Path::new(&self.path()).exists()
+    }
+}
+
+impl Display for Bin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.variant {
+            Some(variant) => write!(f, "{}_{variant}", self.day),
+            None => write!(f, "{}", self.day),
+        }
+    }
+}
+
+/// Returns `true` if `name` can be used as a variant suffix, i.e. it is a non-empty
+/// string of lowercase ascii letters, digits and underscores.
+pub fn is_valid_variant_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+}
+
+/// Discovers the names of all variants of a day by looking for `NN_*.rs` binaries.
+/// The names are returned in sorted order.
+pub fn variants(day: Day) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(paths::bin_dir()) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|file_name| variant_from_file_name(&file_name, day))
+        .collect();
+
+    names.sort_unstable();
+    names
+}
+
+/// All scaffolded binaries of a day, the main solution first followed by its variants.
+pub fn bins(day: Day) -> Vec<Bin> {
+    let main = Bin::main(day);
+    main.exists()
+        .then_some(main)
+        .into_iter()
+        .chain(variants(day).iter().map(|name| Bin::variant(day, name)))
+        .collect()
+}
+
+fn variant_from_file_name(file_name: &str, day: Day) -> Option<String> {
+    let variant = file_name
+        .strip_suffix(".rs")?
+        .strip_prefix(&day.to_string())?
+        .strip_prefix('_')?;
+
+    is_valid_variant_name(variant).then(|| variant.into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Bin, is_valid_variant_name, variant_from_file_name};
+    use crate::day;
+
+    #[test]
+    fn formats_bin_names() {
+        assert_eq!(Bin::main(day!(4)).to_string(), "04");
+        assert_eq!(Bin::variant(day!(4), "viz").to_string(), "04_viz");
+        assert_eq!(Bin::variant(day!(4), "viz").path(), "./src/bin/04_viz.rs");
+    }
+
+    #[test]
+    fn parses_variant_file_names() {
+        assert_eq!(
+            variant_from_file_name("01_no_alloc.rs", day!(1)),
+            Some("no_alloc".into())
+        );
+        assert_eq!(variant_from_file_name("01.rs", day!(1)), None);
+        assert_eq!(variant_from_file_name("01_no_alloc.rs", day!(2)), None);
+        assert_eq!(variant_from_file_name("11_fast.rs", day!(1)), None);
+        assert_eq!(variant_from_file_name("01_.rs", day!(1)), None);
+        assert_eq!(variant_from_file_name("01_viz.txt", day!(1)), None);
+    }
+
+    #[test]
+    fn validates_variant_names() {
+        assert!(is_valid_variant_name("no_alloc"));
+        assert!(is_valid_variant_name("v2"));
+        assert!(!is_valid_variant_name(""));
+        assert!(!is_valid_variant_name("No-Alloc"));
+        assert!(!is_valid_variant_name("../01"));
+    }
+}
@@ -0,0 +1,367 @@
+// Fixed-width bit sets and linear algebra over GF(2), where addition is XOR.
+// Toggling lights with buttons is such a system: pressing a button twice undoes it, so each
+// button is pressed zero times or once and the lights that end up on are the XOR of the buttons.
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor, BitXorAssign};
+
+const WORD: usize = u64::BITS as usize;
+
+/// A set of the bits `0..len`, stored in words. Bits past `len` are always zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// `len` bits, all zero.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD)],
+            len,
+        }
+    }
+
+    /// `len` bits with the bits at `indices` set.
+    pub fn from_indices(len: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut bits = Self::new(len);
+        for i in indices {
+            bits.set(i, true);
+        }
+        bits
+    }
+
+    /// Number of bits, set or not.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bit at `i`, `false` past the end.
+    pub fn get(&self, i: usize) -> bool {
+        i < self.len && self.words[i / WORD] >> (i % WORD) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "bit {i} out of range for {} bits", self.len);
+        let mask = 1 << (i % WORD);
+        if value {
+            self.words[i / WORD] |= mask;
+        } else {
+            self.words[i / WORD] &= !mask;
+        }
+    }
+
+    pub fn flip(&mut self, i: usize) {
+        assert!(i < self.len, "bit {i} out of range for {} bits", self.len);
+        self.words[i / WORD] ^= 1 << (i % WORD);
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Whether no bit is set.
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Indices of the set bits, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                let bit = (word != 0).then(|| word.trailing_zeros() as usize)?;
+                word &= word - 1;
+                Some(i * WORD + bit)
+            })
+        })
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        assert_eq!(self.len, other.len, "bit sets of different lengths");
+        let words = self.words.iter().zip(&other.words);
+        Self {
+            words: words.map(|(&a, &b)| f(a, b)).collect(),
+            len: self.len,
+        }
+    }
+}
+
+impl BitXorAssign<&BitSet> for BitSet {
+    fn bitxor_assign(&mut self, rhs: &BitSet) {
+        assert_eq!(self.len, rhs.len, "bit sets of different lengths");
+        for (a, b) in self.words.iter_mut().zip(&rhs.words) {
+            *a ^= b;
+        }
+    }
+}
+
+impl BitXor for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, rhs: Self) -> BitSet {
+        self.zip_with(rhs, |a, b| a ^ b)
+    }
+}
+
+impl BitAnd for &BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, rhs: Self) -> BitSet {
+        self.zip_with(rhs, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, rhs: Self) -> BitSet {
+        self.zip_with(rhs, |a, b| a | b)
+    }
+}
+
+/// Bits from index 0 on, `#` for set and `.` for unset, like the lights of a machine.
+impl fmt::Display for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.len {
+            f.write_str(if self.get(i) { "#" } else { "." })?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------
+// GF(2) linear systems
+// ------------------------------------------------------------
+
+/// All solutions of a linear system over GF(2): `particular` XOR any combination of the
+/// `null_space` basis vectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gf2Solutions {
+    pub particular: BitSet,
+    pub null_space: Vec<BitSet>,
+}
+
+/// Solves `columns[0] * x0 ^ columns[1] * x1 ^ ... == target` for the bits `x`, by Gaussian
+/// elimination. `None` if there is no solution.
+///
+/// For buttons toggling lights, `columns` are the buttons and `target` the lights to turn on.
+pub fn solve_gf2(columns: &[BitSet], target: &BitSet) -> Option<Gf2Solutions> {
+    let n = columns.len();
+    // One row per equation, the variables followed by the right-hand side.
+    let mut rows: Vec<BitSet> = (0..target.len())
+        .map(|r| {
+            let vars = columns.iter().enumerate().filter(|(_, c)| c.get(r));
+            let mut row = BitSet::from_indices(n + 1, vars.map(|(v, _)| v));
+            row.set(n, target.get(r));
+            row
+        })
+        .collect();
+
+    // Reduced row echelon form, `pivots[i]` is the variable of the leading bit of row `i`.
+    let mut pivots = vec![];
+    for var in 0..n {
+        let rank = pivots.len();
+        let Some(p) = (rank..rows.len()).find(|&r| rows[r].get(var)) else {
+            continue;
+        };
+        rows.swap(rank, p);
+        let pivot = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && row.get(var) {
+                *row ^= &pivot;
+            }
+        }
+        pivots.push(var);
+    }
+    // A remaining row reads `0 == 1`.
+    if rows[pivots.len()..].iter().any(|row| row.get(n)) {
+        return None;
+    }
+
+    // Free variables are zero in the particular solution.
+    let mut particular = BitSet::new(n);
+    for (row, &var) in rows.iter().zip(&pivots) {
+        particular.set(var, row.get(n));
+    }
+    // Setting one free variable forces the pivot variables whose rows contain it.
+    let null_space = (0..n)
+        .filter(|var| !pivots.contains(var))
+        .map(|free| {
+            let mut v = BitSet::from_indices(n, [free]);
+            for (row, &var) in rows.iter().zip(&pivots) {
+                v.set(var, row.get(free));
+            }
+            v
+        })
+        .collect();
+    Some(Gf2Solutions {
+        particular,
+        null_space,
+    })
+}
+
+impl Gf2Solutions {
+    /// A solution with the fewest set bits, e.g. the fewest button presses.
+    ///
+    /// Tries every combination of the null space basis, so the cost doubles with each free
+    /// variable. Combinations are visited in Gray code order, flipping one basis vector per step.
+    /// `None` if there are 64 free variables or more, see [`min_xor_count`] for such systems.
+    pub fn min_weight(&self) -> Option<BitSet> {
+        let free = self.null_space.len();
+        if free >= 64 {
+            return None;
+        }
+        let mut current = self.particular.clone();
+        let mut best = (current.count_ones(), current.clone());
+        for step in 1u64..1 << free {
+            current ^= &self.null_space[step.trailing_zeros() as usize];
+            let weight = current.count_ones();
+            if weight < best.0 {
+                best = (weight, current.clone());
+            }
+        }
+        Some(best.1)
+    }
+}
+
+/// The fewest `columns` whose XOR is `target`, `None` if there are none.
+///
+/// Searches breadth first through the values reachable from zero, at most `2^target.len()` of
+/// them, so it suits systems with few equations and many redundant columns, where
+/// [`Gf2Solutions::min_weight`] would enumerate too many combinations.
+pub fn min_xor_count(columns: &[BitSet], target: &BitSet) -> Option<usize> {
+    let start = BitSet::new(target.len());
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((value, count)) = queue.pop_front() {
+        if value == *target {
+            return Some(count);
+        }
+        for column in columns {
+            let next = &value ^ column;
+            if seen.insert(next.clone()) {
+                queue.push_back((next, count + 1));
+            }
+        }
+    }
+    None
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BitSet, min_xor_count, solve_gf2};
+
+    fn bits(s: &str) -> BitSet {
+        BitSet::from_indices(
+            s.len(),
+            s.char_indices().filter(|&(_, c)| c == '#').map(|(i, _)| i),
+        )
+    }
+
+    #[test]
+    fn sets_bits() {
+        let mut a = BitSet::new(130);
+        a.set(0, true);
+        a.set(129, true);
+        a.flip(64);
+        assert_eq!(a.ones().collect::<Vec<_>>(), [0, 64, 129]);
+        assert_eq!(a.count_ones(), 3);
+        assert!(a.get(64) && !a.get(63) && !a.get(500));
+        a.flip(64);
+        a.set(0, false);
+        let b = BitSet::from_indices(130, [1, 129]);
+        assert_eq!((&a ^ &b).ones().collect::<Vec<_>>(), [1]);
+        assert_eq!(&a & &b, a);
+        assert_eq!(&a | &b, b);
+        assert!((&a ^ &a).is_zero());
+        assert_eq!(bits(".##.").to_string(), ".##.");
+    }
+
+    #[test]
+    fn solves_systems() {
+        // The first machine of the day 10 example, two presses are the fewest.
+        let target = bits(".##.");
+        let buttons = [
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ]
+        .map(|b| BitSet::from_indices(4, b));
+        let solutions = solve_gf2(&buttons, &target).unwrap();
+        assert_eq!(solutions.null_space.len(), 2);
+        let min = solutions.min_weight().unwrap();
+        assert_eq!(min.count_ones(), 2);
+        let mut lights = BitSet::new(4);
+        for b in min.ones() {
+            lights ^= &buttons[b];
+        }
+        assert_eq!(lights, target);
+
+        // Every solution reaches the target.
+        for v in &solutions.null_space {
+            let pressed = &solutions.particular ^ v;
+            let lights = pressed.ones().fold(BitSet::new(4), |l, b| &l ^ &buttons[b]);
+            assert_eq!(lights, target);
+        }
+
+        let zero = solve_gf2(&buttons, &BitSet::new(4)).unwrap();
+        assert!(zero.min_weight().unwrap().is_zero());
+        assert_eq!(solve_gf2(&[bits("#.")], &bits(".#")), None);
+    }
+
+    #[test]
+    fn solves_wide_systems() {
+        // 100 lights, each button toggles a light and its neighbour.
+        let n = 100;
+        let buttons: Vec<BitSet> = (0..n)
+            .map(|i| BitSet::from_indices(n, [i, (i + 1) % n]))
+            .collect();
+        let target = BitSet::from_indices(n, [10, 90]);
+        let solutions = solve_gf2(&buttons, &target).unwrap();
+        assert_eq!(solutions.null_space.len(), 1);
+        // Toggling 10..90 or the rest of the ring.
+        assert_eq!(solutions.min_weight().unwrap().count_ones(), 20);
+    }
+
+    #[test]
+    fn solves_systems_with_many_redundant_columns() {
+        // 70 copies of the buttons of two lights, too many free variables to enumerate.
+        let buttons: Vec<BitSet> = (0..70)
+            .flat_map(|_| [bits("#."), bits(".#"), bits("##")])
+            .collect();
+        let solutions = solve_gf2(&buttons, &bits("##")).unwrap();
+        assert_eq!(solutions.null_space.len(), 208);
+        assert_eq!(solutions.min_weight(), None);
+        assert_eq!(min_xor_count(&buttons, &bits("##")), Some(1));
+        assert_eq!(min_xor_count(&buttons, &bits("..")), Some(0));
+        assert_eq!(min_xor_count(&buttons[..1], &bits(".#")), None);
+
+        // The example machine again.
+        let target = bits(".##.");
+        let buttons = [
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ]
+        .map(|b| BitSet::from_indices(4, b));
+        assert_eq!(min_xor_count(&buttons, &target), Some(2));
+    }
+}
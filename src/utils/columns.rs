@@ -0,0 +1,167 @@
+// Fixed-width columns of text, e.g. a worksheet of numbers written below each other.
+// Columns are byte offsets, so the text should be ASCII. Rows may be ragged: a row that ends
+// early is treated as padded with blanks.
+
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct Columns<'a> {
+    rows: Vec<&'a str>,
+    delimiter: Option<&'a str>,
+    spans: Vec<Range<usize>>,
+}
+
+impl<'a> Columns<'a> {
+    /// Columns separated by columns that are blank in every row.
+    pub fn by_blank_columns(rows: Vec<&'a str>) -> Self {
+        let mut columns = Self {
+            rows,
+            delimiter: None,
+            spans: vec![],
+        };
+        let mut start = None;
+        for c in 0..=columns.width() {
+            match (start, columns.is_blank(c)) {
+                (None, false) => start = Some(c),
+                (Some(s), true) => {
+                    columns.spans.push(s..c);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        columns
+    }
+
+    /// Columns starting at each non-blank character of `delimiter`, e.g. the row of operators
+    /// below a worksheet. Columns that are blank in every row are cut off the end of each span.
+    pub fn by_delimiter_row(rows: Vec<&'a str>, delimiter: &'a str) -> Self {
+        let mut columns = Self {
+            rows,
+            delimiter: Some(delimiter),
+            spans: vec![],
+        };
+        let starts: Vec<usize> = delimiter
+            .bytes()
+            .enumerate()
+            .filter(|&(_, b)| b != b' ')
+            .map(|(c, _)| c)
+            .collect();
+        let width = columns.width();
+        for (i, &start) in starts.iter().enumerate() {
+            let mut end = starts.get(i + 1).copied().unwrap_or(width);
+            while end > start + 1 && columns.is_blank(end - 1) {
+                end -= 1;
+            }
+            columns.spans.push(start..end);
+        }
+        columns
+    }
+
+    /// Length of the longest row, including the delimiter row.
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .chain(&self.delimiter)
+            .map(|row| row.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn spans(&self) -> &[Range<usize>] {
+        &self.spans
+    }
+
+    pub fn blocks(&self) -> impl Iterator<Item = Block<'_, 'a>> {
+        self.spans.iter().map(|span| Block {
+            columns: self,
+            span: span.clone(),
+        })
+    }
+
+    fn is_blank(&self, c: usize) -> bool {
+        self.rows
+            .iter()
+            .chain(&self.delimiter)
+            .all(|row| row.as_bytes().get(c).is_none_or(|&b| b == b' '))
+    }
+}
+
+/// The text of all rows within the span of one column.
+#[derive(Debug, Clone)]
+pub struct Block<'c, 'a> {
+    columns: &'c Columns<'a>,
+    span: Range<usize>,
+}
+
+impl<'a> Block<'_, 'a> {
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The delimiter row within the span, empty for columns split by blank columns.
+    pub fn delimiter(&self) -> &'a str {
+        self.columns
+            .delimiter
+            .map_or("", |row| cut(row, &self.span))
+    }
+
+    /// Rows within the span, as slices of the rows. Ragged rows are cut short instead of padded.
+    pub fn rows(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.columns.rows.iter().map(|row| cut(row, &self.span))
+    }
+
+    /// The block read top to bottom, one string per column, padded with blanks.
+    pub fn columns(&self) -> impl Iterator<Item = String> + '_ {
+        self.span.clone().map(|c| {
+            self.columns
+                .rows
+                .iter()
+                .map(|row| row.as_bytes().get(c).map_or(' ', |&b| b as char))
+                .collect()
+        })
+    }
+}
+
+/// The part of `row` within `span`, empty if the span would split a character.
+fn cut<'a>(row: &'a str, span: &Range<usize>) -> &'a str {
+    let end = span.end.min(row.len());
+    row.get(span.start.min(end)..end).unwrap_or_default()
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Columns;
+
+    #[test]
+    fn splits_by_blank_columns() {
+        let columns = Columns::by_blank_columns(vec!["12  3", " 4  56", "", "7"]);
+        assert_eq!(columns.spans(), &[0..2, 4..6]);
+        let rows: Vec<Vec<_>> = columns.blocks().map(|b| b.rows().collect()).collect();
+        assert_eq!(rows, [vec!["12", " 4", "", "7"], vec!["3", "56", "", ""]]);
+        assert_eq!(columns.blocks().next().unwrap().delimiter(), "");
+    }
+
+    #[test]
+    fn splits_by_delimiter_row() {
+        let rows = vec!["123 328", " 45 64 ", "  6 98"];
+        let columns = Columns::by_delimiter_row(rows, "*   +  ");
+        assert_eq!(columns.spans(), &[0..3, 4..7]);
+
+        let block = columns.blocks().nth(1).unwrap();
+        assert_eq!(block.delimiter(), "+  ");
+        assert_eq!(block.rows().collect::<Vec<_>>(), ["328", "64 ", "98"]);
+        assert_eq!(block.columns().collect::<Vec<_>>(), ["369", "248", "8  "]);
+    }
+
+    #[test]
+    fn cuts_instead_of_splitting_characters() {
+        let columns = Columns::by_delimiter_row(vec!["ä1"], "+++");
+        let rows: Vec<Vec<_>> = columns.blocks().map(|b| b.rows().collect()).collect();
+        assert_eq!(rows, [vec![""], vec![""], vec!["1"]]);
+    }
+}
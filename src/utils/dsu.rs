@@ -0,0 +1,145 @@
+// Disjoint-set union (union-find) over the elements `0..n`.
+// Union by size keeps trees shallow, `find` compresses paths without recursion.
+
+#[derive(Debug, Clone)]
+pub struct Dsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl Dsu {
+    /// `n` elements, each in its own component.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of components.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The root of the component of `x`.
+    #[inline]
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point every element on the path directly to the root.
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Joins the components of `a` and `b`, `false` if they were already joined.
+    #[inline]
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        // Hang the smaller component below the larger one
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the component of `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Sizes of all components, in the order of their roots.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+    }
+
+    /// Sizes of the `k` largest components, largest first.
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.sizes().collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+
+    /// The elements of each component, ordered by their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut components: Vec<Vec<usize>> = vec![];
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = components.len();
+                components.push(vec![]);
+            }
+            components[index[root]].push(x);
+        }
+        components
+    }
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Dsu;
+
+    #[test]
+    fn joins_components() {
+        let mut dsu = Dsu::new(6);
+        assert!(dsu.union(0, 1));
+        assert!(dsu.union(2, 3));
+        assert!(dsu.union(1, 3));
+        assert!(!dsu.union(0, 2));
+        assert_eq!(dsu.count(), 3);
+        assert!(dsu.same(0, 3) && !dsu.same(0, 4));
+        assert_eq!(dsu.size(2), 4);
+        assert_eq!(dsu.largest(2), vec![4, 1]);
+        assert_eq!(dsu.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn handles_long_chains() {
+        // Union by size never builds a chain, build one by hand. One this long would overflow
+        // the stack of a recursive `find`.
+        let n = 1_000_000;
+        let mut dsu = Dsu::new(n);
+        for x in 1..n {
+            dsu.parent[x - 1] = x;
+            dsu.size[x] += dsu.size[x - 1];
+            dsu.count -= 1;
+        }
+        assert_eq!(dsu.find(0), n - 1);
+        assert_eq!(dsu.parent[n / 2], n - 1);
+        assert_eq!((dsu.count(), dsu.size(0)), (1, n));
+    }
+}
@@ -0,0 +1,513 @@
+// Integer geometry: points, bounding boxes and rectilinear polygons on a grid of tiles.
+// Coordinates are signed, so differences of points never underflow. Distances between points are
+// exact, e.g. the squared Euclidean distance instead of a `f64` square root.
+
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, Mul, Neg, Rem, Sub};
+
+/// Signed integer types used as coordinates.
+pub trait Coord:
+    Copy
+    + Ord
+    + Debug
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+
+    fn abs(self) -> Self;
+    /// Division rounding towards zero.
+    fn div(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),+) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TWO: Self = 2;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn div(self, rhs: Self) -> Self {
+                    self / rhs
+                }
+            }
+        )+
+    };
+}
+
+impl_coord!(i32, i64, i128, isize);
+
+fn gcd<T: Coord>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+// ------------------------------------------------------------
+// Points
+// ------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Implements the component-wise operators and distances of a point type.
+macro_rules! impl_point {
+    ($point:ident, $($c:ident),+) => {
+        impl<T: Coord> $point<T> {
+            pub fn new($($c: T),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// Squared Euclidean distance.
+            pub fn dist2(self, other: Self) -> T {
+                T::ZERO $(+ (self.$c - other.$c) * (self.$c - other.$c))+
+            }
+
+            pub fn manhattan(self, other: Self) -> T {
+                T::ZERO $(+ (self.$c - other.$c).abs())+
+            }
+
+            pub fn chebyshev(self, other: Self) -> T {
+                T::ZERO$(.max((self.$c - other.$c).abs()))+
+            }
+
+            /// Component-wise minimum.
+            pub fn min(self, other: Self) -> Self {
+                Self { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            /// Component-wise maximum.
+            pub fn max(self, other: Self) -> Self {
+                Self { $($c: self.$c.max(other.$c)),+ }
+            }
+        }
+
+        impl<T: Coord> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+    };
+}
+
+impl_point!(Point2, x, y);
+impl_point!(Point3, x, y, z);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    /// 2D cross product of the vectors `self` and `other`.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+// ------------------------------------------------------------
+// Bounding boxes
+// ------------------------------------------------------------
+
+/// Axis-aligned box of tiles from `min` to `max`, both inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BBox<T = i64> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coord> BBox<T> {
+    /// The box with opposite corners `a` and `b`.
+    pub fn from_corners(a: Point2<T>, b: Point2<T>) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// The smallest box containing all `points`, `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(
+            points.fold(Self::from_corners(first, first), |bbox, p| Self {
+                min: bbox.min.min(p),
+                max: bbox.max.max(p),
+            }),
+        )
+    }
+
+    /// Number of columns of tiles.
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    /// Number of rows of tiles.
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    /// Number of tiles.
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, p: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    /// Whether the open interiors of the boxes overlap, boxes that only share an edge do not.
+    pub fn overlaps_interior(&self, other: &Self) -> bool {
+        self.min.x < other.max.x
+            && self.min.y < other.max.y
+            && self.max.x > other.min.x
+            && self.max.y > other.min.y
+    }
+}
+
+// ------------------------------------------------------------
+// Polygons
+// ------------------------------------------------------------
+
+/// A closed polygon through lattice points, the last vertex connects back to the first.
+/// [`Polygon::contains`] and [`Polygon::contains_box`] require a rectilinear polygon, i.e. one
+/// whose edges are all horizontal or vertical.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon<T = i64> {
+    vertices: Vec<Point2<T>>,
+}
+
+impl<T: Coord> Polygon<T> {
+    pub fn new(vertices: Vec<Point2<T>>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point2<T>] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Point2<T>, Point2<T>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area enclosed by the vertices (shoelace formula), so it stays an integer.
+    pub fn double_area(&self) -> T {
+        self.edges().map(|(a, b)| a.cross(b)).sum::<T>().abs()
+    }
+
+    /// Number of lattice points on the edges.
+    pub fn boundary_points(&self) -> T {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside, by Pick's theorem `A = I + B/2 - 1`.
+    pub fn interior_points(&self) -> T {
+        (self.double_area() - self.boundary_points() + T::TWO).div(T::TWO)
+    }
+
+    /// Number of lattice points inside or on the polygon, e.g. the tiles dug out along a loop.
+    pub fn lattice_points(&self) -> T {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Whether `p` lies inside or on the edges of the rectilinear polygon.
+    pub fn contains(&self, p: Point2<T>) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let edge = BBox::from_corners(a, b);
+            if edge.contains(p) {
+                return true;
+            }
+            // Cast a ray to the right, vertical edges include their lower end only.
+            if edge.min.x == edge.max.x && edge.min.x > p.x && edge.min.y <= p.y && p.y < edge.max.y
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// The tiles inside or on the edges of the rectilinear polygon, to check many boxes.
+    pub fn tiles(&self) -> Tiles<T> {
+        Tiles::new(self)
+    }
+
+    /// Whether every tile of `bbox` lies inside or on the edges of the rectilinear polygon. Builds
+    /// the [`Tiles`] of the polygon first, use [`Polygon::tiles`] to check many boxes.
+    pub fn contains_box(&self, bbox: &BBox<T>) -> bool {
+        self.tiles().contains_box(bbox)
+    }
+}
+
+/// The tiles inside or on the edges of a rectilinear polygon, grouped into cells.
+///
+/// The rows and columns of the vertices, and the runs of rows and columns in between them, cut the
+/// tiles into cells. Edges only run along the rows and columns of vertices, so the tiles of a cell
+/// are all inside or all outside, and one tile per cell is checked. A box is then checked by
+/// counting the cells with tiles outside it covers, which also accepts a box across a notch one
+/// tile wide: the outside in between its edges holds no tile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tiles<T = i64> {
+    /// The first column of each cell.
+    xs: Vec<T>,
+    /// The first row of each cell.
+    ys: Vec<T>,
+    /// `outside[j][i]` counts the cells with tiles outside among the first `j` rows and `i`
+    /// columns of cells.
+    outside: Vec<Vec<usize>>,
+}
+
+impl<T: Coord> Tiles<T> {
+    fn new(polygon: &Polygon<T>) -> Self {
+        let xs = cells(polygon.vertices.iter().map(|v| v.x));
+        let ys = cells(polygon.vertices.iter().map(|v| v.y));
+        let edges: Vec<BBox<T>> = polygon
+            .edges()
+            .map(|(a, b)| BBox::from_corners(a, b))
+            .collect();
+        let mut outside = vec![vec![0; xs.len() + 1]; ys.len() + 1];
+        for (j, &y) in ys.iter().enumerate() {
+            // The vertical edges a ray along the row crosses, each includes its lower end only.
+            let mut crossings: Vec<T> = edges
+                .iter()
+                .filter(|e| e.min.x == e.max.x && e.min.y <= y && y < e.max.y)
+                .map(|e| e.min.x)
+                .collect();
+            crossings.sort_unstable();
+            // The cells on an edge of the row, counted with a difference array.
+            let mut on_edge = vec![0_i32; xs.len() + 1];
+            for e in edges.iter().filter(|e| e.min.y <= y && y <= e.max.y) {
+                on_edge[cell(&xs, e.min.x)] += 1;
+                on_edge[cell(&xs, e.max.x) + 1] -= 1;
+            }
+            let mut edges_here = 0;
+            for (i, &x) in xs.iter().enumerate() {
+                edges_here += on_edge[i];
+                let to_the_right = crossings.len() - crossings.partition_point(|&c| c <= x);
+                let is_outside = edges_here == 0 && to_the_right.is_multiple_of(2);
+                outside[j + 1][i + 1] =
+                    usize::from(is_outside) + outside[j][i + 1] + outside[j + 1][i] - outside[j][i];
+            }
+        }
+        Self { xs, ys, outside }
+    }
+
+    /// Whether every tile of `bbox` lies inside or on the edges of the polygon.
+    pub fn contains_box(&self, bbox: &BBox<T>) -> bool {
+        let within = |cells: &[T], min: T, max: T| {
+            cells.first().is_some_and(|&first| first <= min)
+                && cells.last().is_some_and(|&last| max <= last)
+        };
+        if !within(&self.xs, bbox.min.x, bbox.max.x) || !within(&self.ys, bbox.min.y, bbox.max.y) {
+            return false;
+        }
+        let (x0, x1) = (cell(&self.xs, bbox.min.x), cell(&self.xs, bbox.max.x) + 1);
+        let (y0, y1) = (cell(&self.ys, bbox.min.y), cell(&self.ys, bbox.max.y) + 1);
+        let outside = &self.outside;
+        outside[y1][x1] + outside[y0][x0] == outside[y0][x1] + outside[y1][x0]
+    }
+}
+
+/// The first row or column of each cell: the coordinates of the vertices, each followed by the
+/// next one if it is not a coordinate of a vertex too.
+fn cells<T: Coord>(coords: impl Iterator<Item = T>) -> Vec<T> {
+    let mut coords: Vec<T> = coords.collect();
+    coords.sort_unstable();
+    coords.dedup();
+    let mut cells = Vec::with_capacity(2 * coords.len());
+    for (i, &c) in coords.iter().enumerate() {
+        cells.push(c);
+        if coords.get(i + 1).is_some_and(|&next| next - c > T::ONE) {
+            cells.push(c + T::ONE);
+        }
+    }
+    cells
+}
+
+/// The cell of the row or column `c`, which must not lie before the first cell.
+fn cell<T: Coord>(cells: &[T], c: T) -> usize {
+    cells.partition_point(|&first| first <= c) - 1
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BBox, Point2, Point3, Polygon, Tiles};
+
+    /// The loop of red tiles of the day 9 example.
+    fn red_tiles() -> Polygon {
+        let corners = [
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ];
+        Polygon::new(corners.map(|(x, y)| Point2::new(x, y)).to_vec())
+    }
+
+    #[test]
+    fn measures_distances() {
+        let (a, b) = (Point3::new(1, 2, 3), Point3::new(4, -2, 3));
+        assert_eq!(a.dist2(b), 25);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(b - a, Point3::new(3, -4, 0));
+        assert_eq!(Point2::new(1, 2) * 3 + Point2::new(0, 1), Point2::new(3, 7));
+    }
+
+    #[test]
+    fn bounds_points() {
+        let points = [Point2::new(2, 5), Point2::new(11, 1), Point2::new(7, 3)];
+        let bbox = BBox::from_points(points).unwrap();
+        assert_eq!(
+            (bbox.min, bbox.max),
+            (Point2::new(2, 1), Point2::new(11, 5))
+        );
+        assert_eq!((bbox.width(), bbox.height(), bbox.area()), (10, 5, 50));
+        assert!(bbox.contains(Point2::new(2, 1)) && !bbox.contains(Point2::new(1, 1)));
+
+        let edge = BBox::from_corners(Point2::new(11, 1), Point2::new(11, 7));
+        assert!(!edge.overlaps_interior(&bbox));
+        assert!(BBox::<i64>::from_points([]).is_none());
+    }
+
+    #[test]
+    fn measures_polygons() {
+        let square = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(4, 0),
+            Point2::new(4, 4),
+            Point2::new(0, 4),
+        ]);
+        assert_eq!(square.double_area(), 32);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.lattice_points(), 25);
+
+        let tiles = red_tiles();
+        assert_eq!(tiles.lattice_points(), 46);
+        assert!(tiles.contains(Point2::new(3, 4)));
+        assert!(tiles.contains(Point2::new(11, 7)));
+        assert!(!tiles.contains(Point2::new(3, 6)));
+        assert!(!tiles.contains(Point2::new(5, 2)));
+    }
+
+    #[test]
+    fn checks_boxes_inside_polygons() {
+        let tiles = red_tiles();
+        let inside =
+            |a, b| tiles.contains_box(&BBox::from_corners(Point2::from(a), Point2::from(b)));
+        assert!(inside((9, 5), (2, 3)));
+        assert!(inside((7, 3), (11, 1)));
+        assert!(inside((9, 7), (9, 5)));
+        assert!(!inside((11, 1), (2, 5)));
+        assert!(!inside((2, 3), (7, 1)));
+    }
+
+    #[test]
+    fn checks_boxes_across_notches() {
+        let polygon =
+            |corners: &[(i64, i64)]| Polygon::new(corners.iter().map(|&c| c.into()).collect());
+        let inside = |tiles: &Tiles, a: (i64, i64), b: (i64, i64)| {
+            tiles.contains_box(&BBox::from_corners(a.into(), b.into()))
+        };
+        // A U whose gap is one tile wide: all of its tiles are on the edges.
+        let thin = polygon(&[
+            (0, 0),
+            (3, 0),
+            (3, 5),
+            (2, 5),
+            (2, 1),
+            (1, 1),
+            (1, 5),
+            (0, 5),
+        ])
+        .tiles();
+        assert!(inside(&thin, (0, 0), (3, 5)));
+        assert!(!inside(&thin, (0, 0), (4, 5)));
+        // The same U two tiles wide has tiles outside in its gap.
+        let wide = polygon(&[
+            (0, 0),
+            (4, 0),
+            (4, 5),
+            (3, 5),
+            (3, 1),
+            (1, 1),
+            (1, 5),
+            (0, 5),
+        ])
+        .tiles();
+        assert!(!inside(&wide, (0, 0), (4, 5)));
+        assert!(inside(&wide, (0, 0), (4, 1)));
+        assert!(inside(&wide, (3, 5), (4, 0)));
+        // The notch of a thin L, no edge cuts through the box.
+        let l = polygon(&[(0, 0), (10, 0), (10, 1), (1, 1), (1, 10), (0, 10)]).tiles();
+        assert!(!inside(&l, (10, 1), (1, 10)));
+        assert!(inside(&l, (10, 1), (0, 0)));
+        assert!(
+            !Polygon::<i64>::new(vec![])
+                .contains_box(&BBox::from_corners((0, 0).into(), (0, 0).into()))
+        );
+    }
+}
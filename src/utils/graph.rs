@@ -0,0 +1,437 @@
+// Directed graphs of named nodes, e.g. devices wired to each other.
+// Nodes are interned to dense ids, so algorithms index plain vectors instead of hashing names.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt::Write;
+
+use super::input::{ParseError, iter_lines, split_once_at};
+
+pub type NodeId = usize;
+
+/// Maps names, borrowed from the input, to dense ids in the order they are first seen.
+#[derive(Debug, Clone, Default)]
+pub struct Interner<'a> {
+    ids: HashMap<&'a str, NodeId>,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    /// The id of `name`, a new one if it was not seen before.
+    pub fn intern(&mut self, name: &'a str) -> NodeId {
+        *self.ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.names.len() - 1
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Graph<'a> {
+    nodes: Interner<'a>,
+    adj: Vec<Vec<NodeId>>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, name: &'a str) -> NodeId {
+        let id = self.nodes.intern(name);
+        if id == self.adj.len() {
+            self.adj.push(vec![]);
+        }
+        id
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
+        self.adj[from].push(to);
+    }
+
+    /// Parses one `node: neighbour neighbour ...` line per node, e.g. `aaa: you hhh`.
+    pub fn parse_adjacency(input: &'a str) -> Result<Self, ParseError> {
+        let mut graph = Self::new();
+        for line in iter_lines(input) {
+            let (node, neighbours) = split_once_at(input, line, ":")?;
+            let from = graph.add_node(node.trim());
+            for neighbour in neighbours.split_whitespace() {
+                let to = graph.add_node(neighbour);
+                graph.add_edge(from, to);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Parses one `from{sep}to` edge per line, e.g. `a-b` with `sep` `"-"`.
+    pub fn parse_edges(input: &'a str, sep: &str) -> Result<Self, ParseError> {
+        let mut graph = Self::new();
+        for line in iter_lines(input) {
+            let (from, to) = split_once_at(input, line, sep)?;
+            let (from, to) = (graph.add_node(from.trim()), graph.add_node(to.trim()));
+            graph.add_edge(from, to);
+        }
+        Ok(graph)
+    }
+
+    /// The graph with every edge in both directions.
+    pub fn undirected(&self) -> Self {
+        let mut graph = self.clone();
+        for (from, to) in self.edges() {
+            graph.add_edge(to, from);
+        }
+        graph
+    }
+
+    pub fn len(&self) -> usize {
+        self.adj.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adj.is_empty()
+    }
+
+    /// The names of the nodes, by id.
+    pub fn nodes(&self) -> &Interner<'a> {
+        &self.nodes
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.nodes.get(name)
+    }
+
+    pub fn neighbours(&self, node: NodeId) -> &[NodeId] {
+        &self.adj[node]
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.adj
+            .iter()
+            .enumerate()
+            .flat_map(|(from, to)| to.iter().map(move |&to| (from, to)))
+    }
+
+    /// Nodes ordered so that every edge points forward, `None` if there is a cycle.
+    pub fn toposort(&self) -> Option<Vec<NodeId>> {
+        self.toposort_within(&vec![true; self.len()], |_| true)
+    }
+
+    /// [`toposort`](Self::toposort) of the nodes marked in `within`, following the edges out of
+    /// the nodes for which `follow` holds. Other edges may form cycles.
+    fn toposort_within(
+        &self,
+        within: &[bool],
+        follow: impl Fn(NodeId) -> bool,
+    ) -> Option<Vec<NodeId>> {
+        let edges = || {
+            self.edges()
+                .filter(|&(from, to)| within[from] && within[to] && follow(from))
+        };
+        let mut in_degree = vec![0; self.len()];
+        for (_, to) in edges() {
+            in_degree[to] += 1;
+        }
+        let nodes = (0..self.len()).filter(|&n| within[n]);
+        let mut order: Vec<NodeId> = nodes.clone().filter(|&n| in_degree[n] == 0).collect();
+        let mut i = 0;
+        while let Some(&node) = order.get(i) {
+            if follow(node) {
+                for &next in self.adj[node].iter().filter(|&&next| within[next]) {
+                    in_degree[next] -= 1;
+                    if in_degree[next] == 0 {
+                        order.push(next);
+                    }
+                }
+            }
+            i += 1;
+        }
+        (order.len() == nodes.count()).then_some(order)
+    }
+
+    /// The nodes on some path from `from` to `to` that avoids `avoid` and ends at its first visit
+    /// of `to`. `from` itself must not be avoided.
+    fn nodes_between(&self, from: NodeId, to: NodeId, avoid: &[NodeId]) -> Vec<bool> {
+        let mut forward = vec![false; self.len()];
+        forward[from] = true;
+        let mut stack = vec![from];
+        while let Some(node) = stack.pop() {
+            if node == to {
+                continue;
+            }
+            for &next in &self.adj[node] {
+                if !forward[next] && !avoid.contains(&next) {
+                    forward[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        let mut incoming = vec![vec![]; self.len()];
+        for (a, b) in self
+            .edges()
+            .filter(|&(a, b)| a != to && forward[a] && forward[b])
+        {
+            incoming[b].push(a);
+        }
+        let mut between = vec![false; self.len()];
+        between[to] = forward[to];
+        let mut stack = vec![to];
+        while let Some(node) = stack.pop() {
+            for &prev in &incoming[node] {
+                if !between[prev] {
+                    between[prev] = true;
+                    stack.push(prev);
+                }
+            }
+        }
+        between
+    }
+
+    /// Number of paths from `from` to `to` that visit every node of `must` and none of `avoid`.
+    /// `None` if a cycle lies on such a path, so there are infinitely many, or if the count
+    /// overflows `u64`. Cycles elsewhere in the graph do not matter.
+    ///
+    /// Paths are counted for every subset of `must` visited so far, so `must` should be small.
+    pub fn count_paths(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        must: &[NodeId],
+        avoid: &[NodeId],
+    ) -> Option<u64> {
+        if avoid.contains(&from) || avoid.contains(&to) {
+            return Some(0);
+        }
+        let between = self.nodes_between(from, to, avoid);
+        let order = self.toposort_within(&between, |node| node != to)?;
+        let bit = |node: NodeId| {
+            must.iter()
+                .position(|&m| m == node)
+                .map_or(0, |i| 1usize << i)
+        };
+        let all = (1usize << must.len()) - 1;
+
+        // Paths from `from` to each node, by the set of `must` nodes they visit.
+        let mut paths = vec![vec![0u64; all + 1]; self.len()];
+        paths[from][bit(from)] = 1;
+        for node in order {
+            if node == to {
+                continue;
+            }
+            for visited in 0..=all {
+                let count = paths[node][visited];
+                if count == 0 {
+                    continue;
+                }
+                for &next in self.adj[node].iter().filter(|&&next| between[next]) {
+                    let slot = &mut paths[next][visited | bit(next)];
+                    *slot = slot.checked_add(count)?;
+                }
+            }
+        }
+        Some(paths[to][all])
+    }
+
+    /// Number of edges on a shortest path from `start` to each node, `None` if unreachable.
+    pub fn bfs(&self, start: NodeId) -> Vec<Option<usize>> {
+        let mut dist = vec![None; self.len()];
+        dist[start] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((node, d)) = queue.pop_front() {
+            for &next in &self.adj[node] {
+                if dist[next].is_none() {
+                    dist[next] = Some(d + 1);
+                    queue.push_back((next, d + 1));
+                }
+            }
+        }
+        dist
+    }
+
+    /// Length of a shortest path from `start` to each node with edges weighted by `weight`,
+    /// `None` if unreachable.
+    pub fn dijkstra(
+        &self,
+        start: NodeId,
+        weight: impl Fn(NodeId, NodeId) -> u64,
+    ) -> Vec<Option<u64>> {
+        let mut dist = vec![None; self.len()];
+        let mut heap = BinaryHeap::from([Reverse((0, start))]);
+        while let Some(Reverse((d, node))) = heap.pop() {
+            if dist[node].is_some() {
+                continue;
+            }
+            dist[node] = Some(d);
+            for &next in &self.adj[node] {
+                if dist[next].is_none() {
+                    heap.push(Reverse((d + weight(node, next), next)));
+                }
+            }
+        }
+        dist
+    }
+
+    /// Nodes connected by edges in either direction, each component sorted by id.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let undirected = self.undirected();
+        let mut seen = vec![false; self.len()];
+        let mut components = vec![];
+        for start in 0..self.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = vec![start];
+            let mut i = 0;
+            while let Some(&node) = component.get(i) {
+                for &next in undirected.neighbours(node) {
+                    if !seen[next] {
+                        seen[next] = true;
+                        component.push(next);
+                    }
+                }
+                i += 1;
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// The graph in the DOT language of Graphviz, e.g. for `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for node in 0..self.len() {
+            writeln!(dot, "    {:?};", self.nodes.name(node)).unwrap();
+        }
+        for (from, to) in self.edges() {
+            let (from, to) = (self.nodes.name(from), self.nodes.name(to));
+            writeln!(dot, "    {from:?} -> {to:?};").unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Graph;
+
+    const DEVICES: &str = "svr: aaa bbb\naaa: fft\nfft: ccc\nbbb: tty\ntty: ccc\nccc: ddd eee\n\
+                           ddd: hub\nhub: fff\neee: dac\ndac: fff\nfff: ggg hhh\nggg: out\nhhh: out\n";
+
+    #[test]
+    fn parses_graphs() {
+        let graph = Graph::parse_adjacency("a: b c\nb: c\n").unwrap();
+        assert_eq!(graph.len(), 3);
+        let (a, c) = (graph.id("a").unwrap(), graph.id("c").unwrap());
+        assert_eq!(graph.neighbours(a).len(), 2);
+        assert!(graph.neighbours(c).is_empty());
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \"a\";\n    \"b\";\n    \"c\";\n    \"a\" -> \"b\";\n    \
+             \"a\" -> \"c\";\n    \"b\" -> \"c\";\n}\n"
+        );
+
+        let graph = Graph::parse_edges("x-y\ny-z\n", "-").unwrap();
+        assert_eq!(graph.edges().count(), 2);
+        let err = Graph::parse_adjacency("a: b\nc d\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "`:`"));
+    }
+
+    #[test]
+    fn counts_paths() {
+        let graph = Graph::parse_adjacency(DEVICES).unwrap();
+        let id = |name| graph.id(name).unwrap();
+        let (svr, out) = (id("svr"), id("out"));
+        assert_eq!(graph.count_paths(svr, out, &[], &[]), Some(8));
+        assert_eq!(
+            graph.count_paths(svr, out, &[id("fft"), id("dac")], &[]),
+            Some(2)
+        );
+        assert_eq!(graph.count_paths(svr, out, &[], &[id("aaa")]), Some(4));
+        assert_eq!(graph.count_paths(out, svr, &[], &[]), Some(0));
+
+        let order = graph.toposort().unwrap();
+        let position = |node| order.iter().position(|&n| n == node).unwrap();
+        assert!(graph.edges().all(|(a, b)| position(a) < position(b)));
+
+        let cyclic = Graph::parse_edges("a-b\nb-a\n", "-").unwrap();
+        assert_eq!(cyclic.toposort(), None);
+        assert_eq!(cyclic.count_paths(0, 1, &[], &[]), Some(1));
+        assert_eq!(cyclic.count_paths(0, 0, &[], &[]), Some(1));
+    }
+
+    #[test]
+    fn counts_paths_around_cycles() {
+        // The cycle c-d is not on a path from a to b, the cycle e-f is.
+        let graph = Graph::parse_edges("a-b\nc-d\nd-c\nc-b\nb-e\ne-f\nf-e\nf-g\n", "-").unwrap();
+        let id = |name| graph.id(name).unwrap();
+        assert_eq!(graph.count_paths(id("a"), id("b"), &[], &[]), Some(1));
+        assert_eq!(graph.count_paths(id("c"), id("b"), &[], &[]), None);
+        assert_eq!(graph.count_paths(id("a"), id("g"), &[], &[]), None);
+        assert_eq!(graph.count_paths(id("a"), id("d"), &[], &[]), Some(0));
+    }
+
+    #[test]
+    fn rejects_overflowing_path_counts() {
+        // 64 diamonds in a row double the paths 64 times.
+        let mut edges = String::new();
+        for i in 0..64 {
+            edges += &format!("n{i}-l{i}\nn{i}-r{i}\nl{i}-n{}\nr{i}-n{}\n", i + 1, i + 1);
+        }
+        let graph = Graph::parse_edges(&edges, "-").unwrap();
+        let id = |name| graph.id(name).unwrap();
+        assert_eq!(
+            graph.count_paths(id("n0"), id("n63"), &[], &[]),
+            Some(1 << 63)
+        );
+        assert_eq!(graph.count_paths(id("n0"), id("n64"), &[], &[]), None);
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let graph = Graph::parse_edges("a-b\nb-c\na-c\nc-d\ne-f\n", "-").unwrap();
+        let id = |name| graph.id(name).unwrap();
+        let dist = graph.bfs(id("a"));
+        assert_eq!(dist[id("c")], Some(1));
+        assert_eq!(dist[id("d")], Some(2));
+        assert_eq!(dist[id("e")], None);
+
+        // The direct edge a-c is expensive.
+        let weight = |from, to| {
+            if (from, to) == (id("a"), id("c")) {
+                10
+            } else {
+                1
+            }
+        };
+        let dist = graph.dijkstra(id("a"), weight);
+        assert_eq!(dist[id("c")], Some(2));
+        assert_eq!(dist[id("d")], Some(3));
+
+        let components = graph.components();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[1], vec![id("e"), id("f")]);
+    }
+}
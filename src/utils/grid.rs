@@ -0,0 +1,298 @@
+// Rectangular grid stored as one flat vector, row by row.
+// Points are `(x, y)` with `x` the column and `y` the row, `(0, 0)` is the top left cell.
+
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use super::input::ParseError;
+
+pub type Point = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours: left, right, up, down.
+pub const DIRS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the 8 neighbours, orthogonal ones first.
+pub const DIRS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns from cells listed row by row.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            (width > 0 && cells.len().is_multiple_of(width)) || cells.is_empty(),
+            "{} cells do not fill rows of {width}",
+            cells.len()
+        );
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Self {
+            cells,
+            width: if height == 0 { 0 } else { width },
+            height,
+        }
+    }
+
+    /// Parses a grid with one character per cell, mapped by `cell`. Empty lines are skipped, all
+    /// other lines must have the same length. Characters mapped to `None` are reported as not
+    /// being the `expected` cell.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let (mut width, mut height) = (0, 0);
+        for row in input.lines().filter(|l| !l.is_empty()) {
+            let len = cells.len();
+            for (i, c) in row.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(ParseError::at(input, &row[i..i + c.len_utf8()], expected));
+                    }
+                }
+            }
+            if height == 0 {
+                width = cells.len();
+            } else if cells.len() - len != width {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!("a row of {width} cells"),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Cells row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Index of a point in [`Grid::cells`].
+    #[inline(always)]
+    pub fn index(&self, (x, y): Point) -> usize {
+        y * self.width + x
+    }
+
+    /// Point of an index in [`Grid::cells`].
+    #[inline(always)]
+    pub fn point(&self, index: usize) -> Point {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[self.index(p)])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        let index = self.index(p);
+        self.contains(p).then(|| &mut self.cells[index])
+    }
+
+    /// The point `(dx, dy)` away from `p`, if it is in the grid.
+    #[inline(always)]
+    pub fn step(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let p = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(p).then_some(p)
+    }
+
+    /// Orthogonal neighbours of `p` in the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRS_4.iter().filter_map(move |&d| self.step(p, d))
+    }
+
+    /// Orthogonal and diagonal neighbours of `p` in the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRS_8.iter().filter_map(move |&d| self.step(p, d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` panics on a width of 0, an empty grid has no rows anyway.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of {}", self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells with their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point(i), cell))
+    }
+
+    /// First point, row by row, whose cell matches.
+    pub fn find(&self, matches: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(matches).map(|i| self.point(i))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::from_vec(width, vec![fill; width * height])
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, |(x, y)| (y, x))
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self {
+        self.remap(self.height, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        self.remap(self.height, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// A grid of `width` columns whose cell at `p` is the cell of `self` at `source(p)`.
+    fn remap(&self, width: usize, source: impl Fn(Point) -> Point) -> Self {
+        let height = self.cells.len().checked_div(width).unwrap_or(0);
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|p| self[source(p)].clone())
+            .collect();
+        Self::from_vec(width, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(
+            self.contains(p),
+            "{p:?} out of {}x{}",
+            self.width,
+            self.height
+        );
+        &self.cells[Grid::index(self, p)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(
+            self.contains(p),
+            "{p:?} out of {}x{}",
+            self.width,
+            self.height
+        );
+        let index = Grid::index(self, p);
+        &mut self.cells[index]
+    }
+}
+
+/// Cells of a row are written next to each other, rows end with a newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = digits("123\n456\n\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.find(|&d| d > 4), Some((1, 1)));
+
+        let err = Grid::parse("12\n3x\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Grid::parse("12\n345\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.expected, "a row of 2 cells");
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        let mut edge: Vec<_> = grid.neighbours4((2, 1)).collect();
+        edge.sort();
+        assert_eq!(edge, vec![(1, 1), (2, 0), (2, 2)]);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = digits("123\n456\n");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_left().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(Grid::<u8>::from_vec(0, vec![]).transpose().height(), 0);
+    }
+}
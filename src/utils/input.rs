@@ -1,7 +1,9 @@
 // Advent of Code parsing helpers.
 // All functions take `input: &str` and return parsed structures.
+//
+// Helpers that can fail have a `try_` counterpart returning a `ParseError` with the position of
+// the offending text, the panicking version panics with its message.
 
-use std::fmt::Debug;
 use std::str::FromStr;
 
 /// Return lines as Vec<&str> (no empty final line).
@@ -19,15 +21,16 @@ pub fn map_lines<T>(input: &str, f: impl Fn(&str) -> T) -> Vec<T> {
 }
 
 /// Generic, works with any type that implements FromStr
-pub fn parse_lines<T: FromStr>(input: &str) -> Vec<T>
-where
-    <T as FromStr>::Err: Debug,
-{
+pub fn parse_lines<T: FromStr>(input: &str) -> Vec<T> {
+    try_parse_lines(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
     input
         .lines()
         .map(|line| line.trim()) // Handle potential carriage returns or spaces
         .filter(|line| !line.is_empty()) // Skip empty lines at end of file
-        .map(|line| line.parse::<T>().expect("Failed to parse line"))
+        .map(|line| parse_at(input, line))
         .collect()
 }
 
@@ -41,17 +44,21 @@ pub fn split_lines(input: &str, sep: char) -> Vec<Vec<&str>> {
 }
 
 /// Loop over lines and split a line and parse each element using FromStr.
-pub fn split_parse_lines<T: FromStr>(input: &str, sep: char) -> Vec<Vec<T>>
-where
-    <T as FromStr>::Err: Debug,
-{
+pub fn split_parse_lines<T: FromStr>(input: &str, sep: char) -> Vec<Vec<T>> {
+    try_split_parse_lines(input, sep).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_split_parse_lines<T: FromStr>(
+    input: &str,
+    sep: char,
+) -> Result<Vec<Vec<T>>, ParseError> {
     input
         .lines()
         .filter(|l| !l.is_empty())
         .map(|l| {
             l.split(sep)
                 .filter(|s| !s.is_empty())
-                .map(|s| s.trim().parse::<T>().expect("parse error"))
+                .map(|s| parse_at(input, s.trim()))
                 .collect()
         })
         .collect()
@@ -59,28 +66,38 @@ where
 
 /// Single delimiter-separated line of i64 values.
 pub fn split_line_i64(line: &str, sep: char) -> Vec<i64> {
+    try_split_line_i64(line, sep).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Errors are positioned in `line`, see [`ParseError::within`] to position them in the input.
+pub fn try_split_line_i64(line: &str, sep: char) -> Result<Vec<i64>, ParseError> {
     line.trim()
         .split(sep)
         .filter(|s| !s.is_empty())
-        .map(|s| s.parse::<i64>().expect("invalid integer"))
+        .map(|s| parse_at(line, s))
         .collect()
 }
 
 /// Single whitespace-separated line of i64 values.
 pub fn whitespace_line_i64(line: &str) -> Vec<i64> {
-    line.split_whitespace()
-        .map(|s| s.parse::<i64>().expect("invalid integer"))
-        .collect()
+    try_whitespace_line_i64(line).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Errors are positioned in `line`, see [`ParseError::within`] to position them in the input.
+pub fn try_whitespace_line_i64(line: &str) -> Result<Vec<i64>, ParseError> {
+    line.split_whitespace().map(|s| parse_at(line, s)).collect()
 }
 
 /// Split a line and parse each element using FromStr.
-pub fn split_line_parse<T: FromStr>(line: &str, sep: char) -> Vec<T>
-where
-    <T as FromStr>::Err: Debug,
-{
+pub fn split_line_parse<T: FromStr>(line: &str, sep: char) -> Vec<T> {
+    try_split_line_parse(line, sep).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Errors are positioned in `line`, see [`ParseError::within`] to position them in the input.
+pub fn try_split_line_parse<T: FromStr>(line: &str, sep: char) -> Result<Vec<T>, ParseError> {
     line.split(sep)
         .filter(|s| !s.is_empty())
-        .map(|s| s.parse::<T>().expect("parse error"))
+        .map(|s| parse_at(line, s))
         .collect()
 }
 
@@ -95,21 +112,32 @@ pub fn char_grid(input: &str) -> Vec<Vec<char>> {
 
 /// Grid of single-digit integers.
 pub fn digit_grid(input: &str) -> Vec<Vec<u8>> {
+    try_digit_grid(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_digit_grid(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
     input
         .lines()
-        .map(|l| l.chars().map(|c| c as u8 - b'0').collect())
+        .map(|l| {
+            l.char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(d) => Ok(d as u8),
+                    None => Err(ParseError::at(input, &l[i..i + c.len_utf8()], "a digit")),
+                })
+                .collect()
+        })
         .collect()
 }
 
 /// Grid of integers separated by whitespace.
 pub fn int_grid(input: &str) -> Vec<Vec<i32>> {
+    try_int_grid(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_int_grid(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
     input
         .lines()
-        .map(|l| {
-            l.split_whitespace()
-                .map(|x| x.parse::<i32>().unwrap())
-                .collect()
-        })
+        .map(|l| l.split_whitespace().map(|x| parse_at(input, x)).collect())
         .collect()
 }
 
@@ -136,16 +164,17 @@ pub fn blocks_of_lines(input: &str) -> Vec<Vec<&str>> {
 }
 
 /// Blocks where each line is parsed..
-pub fn parse_blocks<T: FromStr>(input: &str) -> Vec<Vec<T>>
-where
-    <T as FromStr>::Err: Debug,
-{
+pub fn parse_blocks<T: FromStr>(input: &str) -> Vec<Vec<T>> {
+    try_parse_blocks(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_parse_blocks<T: FromStr>(input: &str) -> Result<Vec<Vec<T>>, ParseError> {
     input
         .split("\n\n")
         .map(|b| {
             b.lines()
                 .filter(|l| !l.is_empty())
-                .map(|l| l.trim().parse::<T>().expect("invalid integer"))
+                .map(|l| parse_at(input, l.trim()))
                 .collect()
         })
         .collect()
@@ -156,34 +185,481 @@ where
 // ------------------------------------------------------------
 
 /// Parses an input where coordinates are listed as "x,y" on lines.
-pub fn parse_coordinate_pairs<T: FromStr>(input: &str, sep: char) -> Vec<(T, T)>
-where
-    <T as FromStr>::Err: Debug,
-{
+pub fn parse_coordinate_pairs<T: FromStr>(input: &str, sep: char) -> Vec<(T, T)> {
+    try_parse_coordinate_pairs(input, sep).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_parse_coordinate_pairs<T: FromStr>(
+    input: &str,
+    sep: char,
+) -> Result<Vec<(T, T)>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (x, y) = line.split_once(sep).expect("Line must contain a comma");
-            (
-                x.trim().parse::<T>().expect("Failed to parse X"),
-                y.trim().parse::<T>().expect("Failed to parse Y"),
-            )
+            let (x, y) = line
+                .split_once(sep)
+                .ok_or_else(|| ParseError::at(input, line, format!("`{sep}`")))?;
+            Ok((parse_at(input, x.trim())?, parse_at(input, y.trim())?))
         })
         .collect()
 }
 
 /// Split a line `"a,b"` into (a, b) as i64.
 pub fn split_i64_pair(line: &str, sep: char) -> (i64, i64) {
+    try_split_i64_pair(line, sep).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Errors are positioned in `line`, see [`ParseError::within`] to position them in the input.
+/// Anything after a second `sep` is ignored.
+pub fn try_split_i64_pair(line: &str, sep: char) -> Result<(i64, i64), ParseError> {
     let mut it = line.split(sep);
-    let a = it.next().unwrap().parse().unwrap();
-    let b = it.next().unwrap().parse().unwrap();
-    (a, b)
+    let a = it.next().unwrap_or(line);
+    let b = it
+        .next()
+        .ok_or_else(|| ParseError::at(line, line, format!("`{sep}`")))?;
+    Ok((parse_at(line, a)?, parse_at(line, b)?))
 }
 
 /// Split a line `"x1 y1"` etc. into Vec<i64>.
 pub fn split_to_i64(line: &str, sep: char) -> Vec<i64> {
+    try_split_to_i64(line, sep).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Errors are positioned in `line`, see [`ParseError::within`] to position them in the input.
+pub fn try_split_to_i64(line: &str, sep: char) -> Result<Vec<i64>, ParseError> {
     line.split(sep)
         .filter(|s| !s.is_empty())
-        .map(|s| s.parse::<i64>().unwrap())
+        .map(|s| parse_at(line, s))
         .collect()
 }
+
+// ------------------------------------------------------------
+// Lazy iterators, which borrow from the input and never allocate
+// ------------------------------------------------------------
+
+/// Trimmed, non-empty lines, as [`lines`] without collecting them.
+pub fn iter_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(|l| l.trim()).filter(|l| !l.is_empty())
+}
+
+/// Trimmed, non-empty fields of a line separated by `sep`.
+pub fn iter_fields(line: &str, sep: char) -> impl Iterator<Item = &str> {
+    line.split(sep).map(|f| f.trim()).filter(|f| !f.is_empty())
+}
+
+/// Blocks separated by blank lines, as [`blocks`] without collecting them.
+pub fn iter_blocks(input: &str) -> impl Iterator<Item = &str> {
+    input.split("\n\n").filter(|b| !b.trim().is_empty())
+}
+
+/// Blocks as iterators over their trimmed, non-empty lines, as [`blocks_of_lines`].
+pub fn iter_block_lines(input: &str) -> impl Iterator<Item = impl Iterator<Item = &str>> {
+    iter_blocks(input).map(iter_lines)
+}
+
+/// Integers anywhere in `s`, e.g. `1`, `-2` and `3` in `"p=1,-2 v=+3"`. A `-` directly before
+/// digits is a sign unless it follows a letter or digit, so `"3-5"` yields `3` and `5`. Numbers
+/// that do not fit an `i64` are errors positioned in `s`.
+pub fn signed_ints(s: &str) -> SignedInts<'_> {
+    SignedInts { s, pos: 0 }
+}
+
+pub struct SignedInts<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl Iterator for SignedInts<'_> {
+    type Item = Result<i64, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.s.as_bytes();
+        let start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let end = start
+            + bytes[start..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - start);
+        self.pos = end;
+
+        let signed = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+        let start = start - signed as usize;
+        let token = &self.s[start..end];
+        Some(parse_int(token.as_bytes()).ok_or_else(|| ParseError::at(self.s, token, "i64")))
+    }
+}
+
+/// Parses ASCII digits to a `u64`, `None` if there are none, any other byte or an overflow.
+/// Faster than [`str::parse`] as it skips the sign and radix handling.
+#[inline]
+pub fn parse_uint(bytes: &[u8]) -> Option<u64> {
+    if bytes.is_empty() {
+        return None;
+    }
+    bytes.iter().try_fold(0u64, |n, &b| {
+        let digit = b.wrapping_sub(b'0');
+        if digit > 9 {
+            return None;
+        }
+        n.checked_mul(10)?.checked_add(u64::from(digit))
+    })
+}
+
+/// Parses ASCII digits with an optional leading `-` to an `i64`, like [`parse_uint`].
+#[inline]
+pub fn parse_int(bytes: &[u8]) -> Option<i64> {
+    match bytes {
+        [b'-', digits @ ..] => {
+            let n = parse_uint(digits)?;
+            // `i64::MIN` has no positive counterpart, negate in the unsigned domain.
+            (n <= i64::MIN.unsigned_abs()).then(|| 0i64.wrapping_sub_unsigned(n))
+        }
+        _ => parse_uint(bytes)?.try_into().ok(),
+    }
+}
+
+// ------------------------------------------------------------
+// Integers and patterns
+// ------------------------------------------------------------
+
+/// Exactly `N` integers found anywhere in `s` by [`signed_ints`], e.g. `ints::<3>("1,-2,3")`.
+/// Errors are positioned in `s`, see [`ParseError::within`] to position them in the input.
+pub fn ints<const N: usize>(s: &str) -> Result<[i64; N], ParseError> {
+    let mut found = [0; N];
+    let mut it = signed_ints(s);
+    for (i, slot) in found.iter_mut().enumerate() {
+        *slot = it
+            .next()
+            .ok_or_else(|| ParseError::end(s, format!("{N} integers, found {i}")))??;
+    }
+    match it.next() {
+        None => Ok(found),
+        Some(_) => Err(ParseError::at(s, s, format!("{N} integers"))),
+    }
+}
+
+/// [`ints`] of every non-empty line of `input`, with errors positioned in `input`.
+pub fn ints_per_line<const N: usize>(input: &str) -> Result<Vec<[i64; N]>, ParseError> {
+    iter_lines(input)
+        .map(|line| ints(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// Splits `s`, a slice of `input`, by a `pattern` in which each `{}` captures text up to the
+/// literal text that follows it, or the rest of `s` for a trailing `{}`. E.g. `"{}x{}: {}"`
+/// captures `["12", "5", "1 0 2"]` from `"12x5: 1 0 2"`.
+///
+/// Panics if the pattern does not have `N` captures or two captures are next to each other.
+pub fn captures<'a, const N: usize>(
+    input: &str,
+    s: &'a str,
+    pattern: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let mut literals = pattern.split("{}");
+    let first = literals.next().unwrap_or_default();
+    assert_eq!(
+        pattern.matches("{}").count(),
+        N,
+        "pattern {pattern:?} does not have {N} captures"
+    );
+    let mut rest = s
+        .strip_prefix(first)
+        .ok_or_else(|| ParseError::at(input, s, format!("`{}`", first.escape_debug())))?;
+
+    let mut found = [""; N];
+    for (i, (slot, literal)) in found.iter_mut().zip(literals).enumerate() {
+        let last = i + 1 == N;
+        assert!(
+            last || !literal.is_empty(),
+            "captures are next to each other in {pattern:?}"
+        );
+        if literal.is_empty() {
+            (*slot, rest) = (rest, &rest[rest.len()..]);
+            continue;
+        }
+        let (capture, after) = rest
+            .split_once(literal)
+            .ok_or_else(|| ParseError::at(input, rest, format!("`{}`", literal.escape_debug())))?;
+        (*slot, rest) = (capture, after);
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "the end of the line"));
+    }
+    Ok(found)
+}
+
+/// Captures of a pattern parsed to the types of a tuple, see [`parse_pattern`].
+pub trait FromCaptures: Sized {
+    fn from_captures(input: &str, s: &str, pattern: &str) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_captures {
+    ($n:literal: $($t:ident $v:ident),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+) {
+            fn from_captures(input: &str, s: &str, pattern: &str) -> Result<Self, ParseError> {
+                let [$($v),+] = captures::<$n>(input, s, pattern)?;
+                Ok(($(parse_at::<$t>(input, $v)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1: A a);
+impl_from_captures!(2: A a, B b);
+impl_from_captures!(3: A a, B b, C c);
+impl_from_captures!(4: A a, B b, C c, D d);
+impl_from_captures!(5: A a, B b, C c, D d, E e);
+impl_from_captures!(6: A a, B b, C c, D d, E e, F f);
+
+/// Parses `s`, a slice of `input`, by a `pattern` of [`captures`], e.g.
+/// `parse_pattern::<(u32, u32, String)>(input, line, "{}x{}: {}")`.
+pub fn parse_pattern<T: FromCaptures>(
+    input: &str,
+    s: &str,
+    pattern: &str,
+) -> Result<T, ParseError> {
+    T::from_captures(input, s, pattern)
+}
+
+// ------------------------------------------------------------
+// Errors
+// ------------------------------------------------------------
+
+/// Malformed input: the text `found` where `expected` was expected, at a 1-based line and column
+/// (in characters) of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `found`, which must be a slice of `input` for the position to be right, e.g. a
+    /// line or a field split off it. Other slices are reported at the end of the input.
+    pub fn at(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (found.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + found.len() <= input.len())
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error at the end of `input`, for input that stops early.
+    pub fn end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    /// Moves an error positioned in `part`, a slice of `input`, to its position in `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let origin = Self::at(input, part, "");
+        Self {
+            line: origin.line + self.line - 1,
+            column: if self.line == 1 {
+                origin.column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        match self.found.as_str() {
+            "" => write!(f, "found nothing"),
+            found => write!(f, "found `{}`", found.escape_debug()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, reporting its position on failure.
+pub fn parse_at<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, std::any::type_name::<T>()))
+}
+
+/// Splits `s`, a slice of `input`, at the first `sep`, reporting its position if there is none.
+pub fn split_once_at<'a>(
+    input: &str,
+    s: &'a str,
+    sep: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::at(input, s, format!("`{}`", sep.escape_debug())))
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        ParseError, captures, ints, ints_per_line, iter_block_lines, iter_fields, iter_lines,
+        parse_at, parse_int, parse_pattern, parse_uint, signed_ints, split_once_at, try_digit_grid,
+        try_parse_coordinate_pairs, try_parse_lines, try_split_i64_pair, try_split_line_i64,
+    };
+
+    #[test]
+    fn reports_positions() {
+        let input = "1,2\n3;4\nä,x\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = split_once_at(input, line, ",").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected `,`, found `3;4`"
+        );
+
+        let err = parse_at::<u8>(input, &input[input.len() - 2..input.len() - 1]).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (3, 3, "u8"));
+        assert_eq!(parse_at::<u8>(input, &input[..1]), Ok(1));
+    }
+
+    #[test]
+    fn reports_end_of_input() {
+        let err = ParseError::end("12\n3", "`,`");
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected `,`, found nothing"
+        );
+        let err = ParseError::at("12", "other", "x");
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+
+    #[test]
+    fn fallible_helpers_report_positions() {
+        let err = try_parse_lines::<u32>("1\n2\n  x3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected u32, found `x3`"
+        );
+        assert_eq!(try_parse_lines::<u32>("1\n\n2\n"), Ok(vec![1, 2]));
+
+        let err = try_parse_coordinate_pairs::<i64>("1,2\n3 4\n", ',').unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "3 4"));
+
+        let err = try_digit_grid("12\n3ä\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "ä"));
+    }
+
+    #[test]
+    fn positions_line_errors_within_the_input() {
+        let input = "a: 1,2\nb: 3,y\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = try_split_i64_pair(&line[3..], ',').unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        let err = err.within(input, &line[3..]);
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let err = try_split_i64_pair("7", ',').unwrap_err();
+        assert_eq!(err.expected, "`,`");
+    }
+
+    #[test]
+    fn converts_into_anyhow() {
+        fn sum(input: &str) -> anyhow::Result<i64> {
+            Ok(try_split_line_i64(input, ',')?.iter().sum())
+        }
+        assert_eq!(sum("1,2,3").unwrap(), 6);
+        let err = sum("1,-,3").unwrap_err();
+        assert_eq!(err.downcast_ref::<ParseError>().map(|e| e.column), Some(3));
+    }
+
+    #[test]
+    fn iterates_lazily() {
+        let input = "a, b,,c\n\n  d \n\ne\n\n\n";
+        assert_eq!(iter_lines(input).collect::<Vec<_>>(), ["a, b,,c", "d", "e"]);
+        assert_eq!(
+            iter_fields("a, b,,c", ',').collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        let blocks: Vec<Vec<_>> = iter_block_lines(input).map(Iterator::collect).collect();
+        assert_eq!(blocks, [vec!["a, b,,c"], vec!["d"], vec!["e"]]);
+    }
+
+    #[test]
+    fn finds_signed_ints() {
+        let ints = |s| signed_ints(s).collect::<Result<Vec<_>, _>>();
+        assert_eq!(ints("p=1,-2 v=+3"), Ok(vec![1, -2, 3]));
+        assert_eq!(ints("-4 3-5 x-6"), Ok(vec![-4, 3, 5, 6]));
+        assert_eq!(ints("no numbers"), Ok(vec![]));
+        let err = ints("1 99999999999999999999").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (3, "i64"));
+    }
+
+    #[test]
+    fn parses_ints_from_bytes() {
+        assert_eq!(parse_uint(b"0"), Some(0));
+        assert_eq!(parse_uint(b"18446744073709551615"), Some(u64::MAX));
+        assert_eq!(parse_uint(b"18446744073709551616"), None);
+        assert_eq!(parse_uint(b""), None);
+        assert_eq!(parse_uint(b"1a"), None);
+        assert_eq!(parse_uint(b"-1"), None);
+        assert_eq!(parse_int(b"-9223372036854775808"), Some(i64::MIN));
+        assert_eq!(parse_int(b"9223372036854775808"), None);
+        assert_eq!(parse_int(b"-"), None);
+        assert_eq!(parse_int(b"-42"), Some(-42));
+    }
+
+    #[test]
+    fn extracts_ints() {
+        assert_eq!(ints::<3>("162,817,-812"), Ok([162, 817, -812]));
+        let err = ints::<3>("1,2").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (4, "3 integers, found 2")
+        );
+        let err = ints::<2>("1,2,3").unwrap_err();
+        assert_eq!(err.expected, "2 integers");
+
+        assert_eq!(ints_per_line::<2>("1,2\n\n3,4\n"), Ok(vec![[1, 2], [3, 4]]));
+        let err = ints_per_line::<2>("1,2\n3,4,5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn parses_patterns() {
+        let input = "12x5: 1 0 2\n4x4 3\n";
+        let line = input.lines().next().unwrap();
+        assert_eq!(captures(input, line, "{}x{}: {}"), Ok(["12", "5", "1 0 2"]));
+        assert_eq!(
+            parse_pattern::<(u32, u32, String)>(input, line, "{}x{}: {}"),
+            Ok((12, 5, "1 0 2".to_string()))
+        );
+        assert_eq!(parse_pattern(input, "p=<3>", "p=<{}>"), Ok((3u8,)));
+
+        let line = input.lines().nth(1).unwrap();
+        let err = captures::<3>(input, line, "{}x{}: {}").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 3, "`: `")
+        );
+        let err = captures::<1>(input, "xb!", "a{}!").unwrap_err();
+        assert_eq!(err.expected, "`a`");
+        let err = captures::<1>(input, "[1]x", "[{}]").unwrap_err();
+        assert_eq!(err.expected, "the end of the line");
+    }
+}
@@ -0,0 +1,277 @@
+// Sets of integers stored as sorted, disjoint inclusive ranges, e.g. ID ranges like `3-5`.
+// Ranges that overlap or touch are merged, so `3-5` and `6-8` are stored as `3-8`.
+
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use super::input::{ParseError, iter_fields, parse_at, split_once_at};
+
+/// Integer types that can bound an [`IntervalSet`].
+pub trait Bound: Copy + Ord + Debug {
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+    /// Number of values in `lo..=hi`, which must not be empty.
+    fn count(lo: Self, hi: Self) -> u128;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),+) => {
+        $(
+            impl Bound for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(lo: Self, hi: Self) -> u128 {
+                    (hi as i128 - lo as i128) as u128 + 1
+                }
+            }
+        )+
+    };
+}
+
+impl_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorted, disjoint inclusive ranges with gaps in between.
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|&(lo, hi)| T::count(lo, hi)).sum()
+    }
+
+    /// Adds the inclusive range `lo..=hi`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, lo: T, hi: T) {
+        assert!(lo <= hi, "empty range {lo:?}-{hi:?}");
+        // Ranges that end more than one value before `lo` stay, as do those starting after `hi + 1`.
+        let start = self
+            .ranges
+            .partition_point(|&(_, b)| b.successor().is_some_and(|next| next < lo));
+        let end = self
+            .ranges
+            .partition_point(|&(a, _)| hi.successor().is_none_or(|next| a <= next));
+        let merged = match self.ranges.get(start..end) {
+            Some([first, .., last]) => (lo.min(first.0), hi.max(last.1)),
+            Some([only]) => (lo.min(only.0), hi.max(only.1)),
+            _ => (lo, hi),
+        };
+        self.ranges.splice(start..end, [merged]);
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, hi)| hi < x);
+        self.ranges.get(i).is_some_and(|&(lo, _)| lo <= x)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for &(lo, hi) in &other.ranges {
+            union.insert(lo, hi);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_lo, a_hi)), Some(&(b_lo, b_hi))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (lo, hi) = (a_lo.max(b_lo), a_hi.min(b_hi));
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+            // Drop the range that ends first, the other may overlap the next one.
+            if a_hi < b_hi {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Values of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for &(lo, hi) in &self.ranges {
+            // Skip ranges of `other` that end before this one.
+            while other.ranges.get(j).is_some_and(|&(_, b_hi)| b_hi < lo) {
+                j += 1;
+            }
+            let mut from = Some(lo);
+            for &(b_lo, b_hi) in other.ranges[j..].iter().take_while(|r| r.0 <= hi) {
+                let Some(start) = from else { break };
+                if start < b_lo {
+                    ranges.push((start, b_lo.predecessor().unwrap()));
+                }
+                from = b_hi.successor().filter(|&next| next <= hi);
+            }
+            if let Some(start) = from {
+                ranges.push((start, hi));
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: Bound> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut ranges: Vec<(T, T)> = iter.into_iter().collect();
+        ranges.sort_unstable();
+        let mut set = Self::new();
+        for (lo, hi) in ranges {
+            assert!(lo <= hi, "empty range {lo:?}-{hi:?}");
+            match set.ranges.last_mut() {
+                // Ranges are inclusive, so a range starting right after the last one touches it.
+                Some(last) if last.1.successor().is_none_or(|next| lo <= next) => {
+                    last.1 = last.1.max(hi);
+                }
+                _ => set.ranges.push((lo, hi)),
+            }
+        }
+        set
+    }
+}
+
+/// Splits `lo..=hi` into ranges of numbers with the same number of decimal digits, e.g. `95-1200`
+/// into `95-99`, `100-999` and `1000-1200`.
+pub fn split_by_digit_length(lo: u64, hi: u64) -> impl Iterator<Item = (u64, u64)> {
+    let mut next = (lo <= hi).then_some(lo);
+    std::iter::from_fn(move || {
+        let start = next?;
+        let end = 10u64
+            .checked_pow(start.checked_ilog10().unwrap_or(0) + 1)
+            .map_or(hi, |limit| hi.min(limit - 1));
+        next = end.checked_add(1).filter(|&n| n <= hi);
+        Some((start, end))
+    })
+}
+
+// ------------------------------------------------------------
+// Parsing
+// ------------------------------------------------------------
+
+/// Parses a range `a-b` with `a <= b`, a slice of `input`.
+pub fn parse_range<T: FromStr + Ord>(input: &str, s: &str) -> Result<(T, T), ParseError> {
+    let (a, b) = split_once_at(input, s, "-")?;
+    let (a, b) = (parse_at::<T>(input, a)?, parse_at::<T>(input, b)?);
+    if a > b {
+        return Err(ParseError::at(input, s, "a range `a-b` with a <= b"));
+    }
+    Ok((a, b))
+}
+
+/// Parses ranges separated by `sep`, e.g. `3-5,10-14` or one range per line with `'\n'`. Fields
+/// are trimmed and empty ones skipped.
+pub fn parse_ranges<T: FromStr + Ord>(
+    input: &str,
+    s: &str,
+    sep: char,
+) -> Result<Vec<(T, T)>, ParseError> {
+    iter_fields(s, sep)
+        .map(|range| parse_range(input, range))
+        .collect()
+}
+
+// ------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{IntervalSet, parse_ranges, split_by_digit_length};
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn merges_ranges() {
+        let mut s = set(&[(10, 14), (3, 5), (16, 20), (12, 18)]);
+        assert_eq!(s.ranges(), &[(3, 5), (10, 20)]);
+        s.insert(6, 8);
+        assert_eq!(s.ranges(), &[(3, 8), (10, 20)]);
+        s.insert(9, 9);
+        assert_eq!(s.ranges(), &[(3, 20)]);
+        s.insert(30, 31);
+        s.insert(-1, 0);
+        assert_eq!(s.ranges(), &[(-1, 0), (3, 20), (30, 31)]);
+        assert_eq!(s.len(), 2 + 18 + 2);
+        assert!(s.contains(20) && !s.contains(21) && !s.contains(-2));
+
+        let mut full = IntervalSet::new();
+        full.insert(u8::MAX, u8::MAX);
+        full.insert(0, 254);
+        assert_eq!((full.ranges(), full.len()), (&[(0, u8::MAX)][..], 256));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(4, 12), (15, 15), (19, 25)]);
+        assert_eq!(a.union(&b).ranges(), &[(1, 25)]);
+        assert_eq!(
+            a.intersection(&b).ranges(),
+            &[(4, 5), (10, 12), (15, 15), (19, 20)]
+        );
+        assert_eq!(a.difference(&b).ranges(), &[(1, 3), (13, 14), (16, 18)]);
+        assert_eq!(b.difference(&a).ranges(), &[(6, 9), (21, 25)]);
+        assert!(a.difference(&a).is_empty());
+
+        let max = IntervalSet::from_iter([(250u8, u8::MAX)]);
+        let tail = IntervalSet::from_iter([(252u8, u8::MAX)]);
+        assert_eq!(max.difference(&tail).ranges(), &[(250, 251)]);
+    }
+
+    #[test]
+    fn splits_by_digit_length() {
+        let split: Vec<_> = split_by_digit_length(95, 20065).collect();
+        assert_eq!(split, [(95, 99), (100, 999), (1000, 9999), (10000, 20065)]);
+        assert_eq!(split_by_digit_length(0, 9).collect::<Vec<_>>(), [(0, 9)]);
+        assert_eq!(split_by_digit_length(5, 4).count(), 0);
+        let last = split_by_digit_length(u64::MAX - 1, u64::MAX).last();
+        assert_eq!(last, Some((u64::MAX - 1, u64::MAX)));
+    }
+
+    #[test]
+    fn parses_ranges() {
+        let input = "11-22,95-115\n";
+        assert_eq!(
+            parse_ranges::<u64>(input, input, ','),
+            Ok(vec![(11, 22), (95, 115)])
+        );
+        let input = "3-5\n\n10-x\n";
+        let err = parse_ranges::<u64>(input, input, '\n').unwrap_err();
+        assert_eq!((err.line, err.column), (3, 4));
+        let err = parse_ranges::<u64>("5-3", "5-3", ',').unwrap_err();
+        assert_eq!(err.expected, "a range `a-b` with a <= b");
+    }
+}
@@ -1 +1,9 @@
+pub mod bits;
+pub mod columns;
+pub mod dsu;
+pub mod geom;
+pub mod graph;
+pub mod grid;
 pub mod input;
+pub mod intervals;
+pub mod rng;